@@ -0,0 +1,37 @@
+// Measures the expansion of `#[derive(Builder)]` and `#[builder]` for inputs
+// of growing size, to catch generators whose cost grows faster than the number
+// of fields. Run with:
+//
+//   cargo bench -p derive_builder_bench
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use derive_builder_bench::{large_fn, large_fn_args, large_struct};
+use derive_builder_core::{generate_derive_code, generate_fn_builder_code};
+use std::hint::black_box;
+
+const SIZES: [usize; 4] = [10, 50, 200, 1000];
+
+fn derive(c: &mut Criterion) {
+    let mut group = c.benchmark_group("derive");
+    for size in SIZES {
+        let input = large_struct(size);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &input, |b, input| {
+            b.iter(|| generate_derive_code(black_box(input)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn fn_builder(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fn_builder");
+    for size in SIZES {
+        let item = large_fn(size);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &item, |b, item| {
+            b.iter(|| generate_fn_builder_code(large_fn_args(), black_box(item.clone())).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, derive, fn_builder);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+//! Inputs for measuring how long the expansion of `#[derive(Builder)]` takes
+//! on large structs, see `benches/expand.rs`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+/// A struct with `fields` fields, cycling through the kinds of fields the
+/// builder treats differently: required, optional, defaulted, filled with
+/// `each`, and constrained.
+pub fn large_struct(fields: usize) -> DeriveInput {
+    let fields = (0..fields).map(|index| {
+        let name = format_ident!("field{}", index);
+        let each = format!("item{}", index);
+        let doc = format!(" The field number {}.", index);
+        match index % 5 {
+            0 => quote! { #[doc = #doc] #name: String },
+            1 => quote! { #[doc = #doc] #name: Option<u32> },
+            2 => quote! { #[doc = #doc] #[builder(default = "7")] #name: u64 },
+            3 => quote! { #[doc = #doc] #[builder(each = #each)] #name: Vec<String> },
+            _ => quote! { #[doc = #doc] #[builder(range(min = 0, max = 100))] #name: i32 },
+        }
+    });
+    syn::parse2(quote! {
+        #[builder(derive(Debug, Clone), patch, track_source)]
+        pub struct Large {
+            #(#fields),*
+        }
+    })
+    .expect("the generated struct is valid")
+}
+
+/// A function with `args` arguments, for `#[builder]`.
+pub fn large_fn(args: usize) -> syn::Item {
+    let args = (0..args).map(|index| {
+        let name = format_ident!("arg{}", index);
+        quote! { #name: u32 }
+    });
+    syn::parse2(quote! {
+        pub fn large(#(#args),*) {}
+    })
+    .expect("the generated function is valid")
+}
+
+/// The arguments of `#[builder(...)]` used with [`large_fn`].
+pub fn large_fn_args() -> TokenStream {
+    quote! { derive(Debug) }
+}
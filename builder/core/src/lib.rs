@@ -0,0 +1,3776 @@
+//! The code generation behind `#[derive(Builder)]` and `#[builder]`, which the
+//! `derive_builder` crate wraps in procedural macros.
+//!
+//! It works on `proc_macro2` tokens only, so it can also run outside of a
+//! macro expansion: to unit-test expansions, to measure them, or to generate
+//! builders from code-generation tools.
+//!
+//! ```
+//! let input: syn::DeriveInput = syn::parse_quote! {
+//!     pub struct Command {
+//!         executable: String,
+//!     }
+//! };
+//! let output = derive_builder_core::generate_derive_code(&input).unwrap();
+//! assert!(output.to_string().contains("pub struct CommandBuilder"));
+//! ```
+
+use std::collections::hash_map::{Entry, HashMap};
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    ext::IdentExt, spanned::Spanned, visit_mut::VisitMut, Attribute, Data, DeriveInput, Expr,
+    Field, Fields, FnArg, GenericArgument, GenericParam, Ident, ImplItem, Item, ItemImpl, Lifetime,
+    Lit, LitStr, Meta, MetaList, NestedMeta, Pat, Path, PathArguments, ReturnType, Signature, Type,
+    TypeReference, Visibility,
+};
+
+/// Generates the builder of the struct that `#[derive(Builder)]` is applied to.
+pub fn generate_derive_code(inputtree: &DeriveInput) -> Result<TokenStream, syn::Error> {
+    let parsed = ParsedStruct::new(inputtree)?;
+    generate_builder_code(&parsed, &[])
+}
+
+/// The derive input with its `#[builder]` attributes parsed. It is computed
+/// once per expansion and borrowed by every check and generator, so that the
+/// attributes of a field are parsed once rather than once per generated item.
+struct ParsedStruct<'a> {
+    inputtree: &'a DeriveInput,
+    attributes: ParsedStructAttributes,
+    fields: Vec<ParsedField<'a>>,
+}
+
+impl<'a> ParsedStruct<'a> {
+    fn new(inputtree: &'a DeriveInput) -> Result<Self, syn::Error> {
+        Ok(Self {
+            inputtree,
+            attributes: ParsedStructAttributes::new(&inputtree.attrs)?,
+            fields: get_parsed_field(inputtree)?,
+        })
+    }
+}
+
+/// Generates the builder of `parsed`. `extramethods` names methods the caller
+/// adds to the builder, as for [`check_method_names`].
+fn generate_builder_code(
+    parsed: &ParsedStruct,
+    extramethods: &[&str],
+) -> Result<TokenStream, syn::Error> {
+    let structattributes = &parsed.attributes;
+    if structattributes.constbuilder {
+        check_const_builder_fields(parsed)?;
+    }
+    if !structattributes.asyncbuild {
+        check_sync_builder_fields(parsed)?;
+    }
+    check_method_names(parsed, extramethods)?;
+    check_field_relations(parsed)?;
+    let builderfactorycode = generate_builder_factory(parsed)?;
+    let builderstructcode = generate_builder_struct(parsed)?;
+    let buildererrorcode = if structattributes.constbuilder {
+        TokenStream::new()
+    } else {
+        generate_builder_error(parsed)?
+    };
+    let fieldinfocode = generate_builder_field_info(parsed)?;
+    let builderimplcode = generate_builder_impl(parsed)?;
+    let clicode = if structattributes.cli {
+        generate_cli(parsed)?
+    } else if let Some(span) = parsed.fields.iter().find_map(|field| field.cliskip) {
+        return Err(syn::Error::new(
+            span,
+            "`cli(skip)` needs `#[builder(cli)]` on the struct",
+        ));
+    } else {
+        TokenStream::new()
+    };
+    let patchcode = if structattributes.patch {
+        generate_patch(parsed)?
+    } else {
+        TokenStream::new()
+    };
+
+    let output = join([
+        builderfactorycode,
+        builderstructcode,
+        buildererrorcode,
+        fieldinfocode,
+        builderimplcode,
+        clicode,
+        patchcode,
+    ]);
+    // eprintln!("DEBUG TOKENS: {}", output);
+    Ok(output)
+}
+
+fn join(iter: impl IntoIterator<Item = TokenStream>) -> TokenStream {
+    let mut output = TokenStream::new();
+    for it in iter {
+        output.extend(it)
+    }
+    output
+}
+
+fn check_sync_builder_fields(parsed: &ParsedStruct) -> Result<(), syn::Error> {
+    for field in &parsed.fields {
+        if let Some(default) = field.defaultattr.as_ref().filter(|it| it.asynchronous) {
+            return Err(syn::Error::new(
+                default.expr.span(),
+                "`default_async` needs an async build method, \
+                 add `#[builder(build_fn(async))]` to the struct",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that no two methods of the builder get the same name, which rustc
+/// would otherwise report as a duplicate definition inside the macro output.
+/// `extra` names methods added to the builder by the caller, such as `call`
+/// for function builders.
+///
+/// `builder()` is defined on the struct itself rather than on the builder, so
+/// a field named `builder` does not collide with it.
+fn check_method_names(parsed: &ParsedStruct, extra: &[&str]) -> Result<(), syn::Error> {
+    struct Method {
+        name: String,
+        description: String,
+        /// The option or field that generates the method, for methods
+        /// generated from a field.
+        origin: Option<(Span, String)>,
+        /// Whether the method comes from an `each` or `alias` option.
+        fromoption: bool,
+    }
+    let structattributes = &parsed.attributes;
+    let mut reserved = vec!["new", "build", "FIELDS"];
+    if structattributes.patch {
+        reserved.push("build_partial");
+    }
+    if structattributes.tracksource {
+        reserved.extend(["source_of", "source_label"]);
+    }
+    if structattributes.cli {
+        // `help` would also clash with the `--help` argument.
+        reserved.extend(["parse_args", "help"]);
+    }
+    reserved.extend(extra);
+    let mut methods: Vec<Method> = reserved
+        .into_iter()
+        .map(|name| Method {
+            name: name.to_owned(),
+            description: format!("the builder's own `{}` method", name),
+            origin: None,
+            fromoption: false,
+        })
+        .collect();
+    for field in &parsed.fields {
+        if field.should_have_set_method() {
+            methods.push(Method {
+                name: field.display_name(),
+                description: format!("the setter of `{}`", field.display_name()),
+                origin: Some((field.name().span(), "rename the field".to_owned())),
+                fromoption: false,
+            });
+        }
+        for alias in &field.aliases {
+            methods.push(Method {
+                name: alias.unraw().to_string(),
+                description: format!("an alias of `{}`", field.display_name()),
+                origin: Some((alias.span(), "choose another alias".to_owned())),
+                fromoption: true,
+            });
+        }
+        if let Some(vecattr) = &field.vecattr {
+            let name = vecattr.method.unraw().to_string();
+            methods.push(Method {
+                description: format!("the element setter of `{}`", field.display_name()),
+                origin: Some((
+                    vecattr.method.span(),
+                    format!("choose another name, e.g. `each = \"push_{}\"`", name),
+                )),
+                name,
+                fromoption: true,
+            });
+        }
+    }
+    let mut seen: HashMap<&str, &Method> = HashMap::new();
+    for method in &methods {
+        let other = match seen.entry(&method.name) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                entry.insert(method);
+                continue;
+            }
+        };
+        // Blame an `each` or `alias` option if one is involved, since it is
+        // the easier one to change, and otherwise the method that comes last.
+        let (blamed, kept) = if other.fromoption && !method.fromoption {
+            (other, method)
+        } else {
+            (method, other)
+        };
+        let (span, suggestion) = blamed
+            .origin
+            .as_ref()
+            .expect("the builder's own methods have distinct names");
+        let mut err = syn::Error::new(
+            *span,
+            format!(
+                "`{}` would be generated twice, as {} and as {}; {}",
+                method.name, blamed.description, kept.description, suggestion
+            ),
+        );
+        if let Some((span, _)) = &kept.origin {
+            err.combine(syn::Error::new(
+                *span,
+                format!("{} is generated from here", kept.description),
+            ));
+        }
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Checks that the fields named by `one_of`, `requires` and `conflicts` exist
+/// and that whether they are set can be told from the builder.
+fn check_field_relations(parsed: &ParsedStruct) -> Result<(), syn::Error> {
+    let fields = &parsed.fields;
+    for relation in &parsed.attributes.relations {
+        for name in relation.fields() {
+            let field = match fields
+                .iter()
+                .find(|field| field.name().unraw() == name.unraw())
+            {
+                Some(field) => field,
+                None => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!(
+                            "`{}` names no field of `{}`",
+                            name.unraw(),
+                            parsed.inputtree.ident
+                        ),
+                    ))
+                }
+            };
+            let reason = if field.requiredarg {
+                "is a `required_arg`, which is always set"
+            } else if field.storageattr.is_some() {
+                "uses `field(...)`, whose storage has no notion of being set"
+            } else if !field.cfgattrs.is_empty() {
+                "is conditionally compiled"
+            } else {
+                continue;
+            };
+            let mut err = syn::Error::new(
+                name.span(),
+                format!(
+                    "`{}` cannot be used in `{}`, since the field {}",
+                    name.unraw(),
+                    relation.keyword(),
+                    reason
+                ),
+            );
+            err.combine(syn::Error::new(
+                field.name().span(),
+                format!("`{}` declared here", field.display_name()),
+            ));
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+fn check_const_builder_fields(parsed: &ParsedStruct) -> Result<(), syn::Error> {
+    let structattributes = &parsed.attributes;
+    if let Some(validate) = &structattributes.validate {
+        return Err(syn::Error::new(
+            validate.span(),
+            "`build_fn(validate = \"...\")` is not supported by `#[builder(const)]` builders",
+        ));
+    }
+    if let Some(relation) = structattributes.relations.first() {
+        return Err(syn::Error::new(
+            relation.fields()[0].span(),
+            format!(
+                "`{}(...)` is not supported by `#[builder(const)]` builders",
+                relation.keyword()
+            ),
+        ));
+    }
+    for field in &parsed.fields {
+        if let Some(vecattr) = &field.vecattr {
+            return Err(syn::Error::new(
+                vecattr.method.span(),
+                "`each` is not supported by `#[builder(const)]` builders, \
+                 because `Vec::push` is not a `const fn`; set the whole field instead",
+            ));
+        }
+        if field.storageattr.is_some() {
+            return Err(syn::Error::new(
+                field.field.span(),
+                "`field(...)` is not supported by `#[builder(const)]` builders",
+            ));
+        }
+        if field.setterwrap.is_some() {
+            return Err(syn::Error::new(
+                field.field.span(),
+                "`setter(wrap)` is not supported by `#[builder(const)]` builders, \
+                 because the wrapping conversions are not `const fn`s",
+            ));
+        }
+        if let Some(constraint) = field.constraints.first() {
+            return Err(syn::Error::new(
+                constraint.span(),
+                format!(
+                    "`{}` is not supported by `#[builder(const)]` builders",
+                    constraint.text()
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn generate_builder_factory(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let structname = &inputtree.ident;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let constness = get_constness(parsed);
+    let (impl_generics, ty_generics, where_clause) = inputtree.generics.split_for_impl();
+    let (params, args) = generate_required_args(&parsed.fields);
+    let doc = format!(
+        "Creates a [`{}`] with {}, to construct a new [`{}`].",
+        builderstructname,
+        describe_required_args(&parsed.fields),
+        structname
+    );
+    let output = quote! {
+        impl #impl_generics #structname #ty_generics #where_clause {
+            #[doc = #doc]
+            pub #constness fn builder(#(#params),*) -> #builderstructname #ty_generics {
+                #builderstructname::new(#(#args),*)
+            }
+        }
+    };
+    Ok(output)
+}
+
+/// The parameters taking the `required_arg` fields, in the order of the
+/// fields, and the arguments forwarding them to another call.
+fn generate_required_args(fields: &[ParsedField]) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    fields
+        .iter()
+        .filter(|field| field.requiredarg)
+        .map(|field| {
+            let setter = field.setter_name();
+            let ty = field.ty();
+            let cfgattrs = &field.cfgattrs;
+            (
+                quote! { #(#cfgattrs)* #setter: #ty },
+                quote! { #(#cfgattrs)* #setter },
+            )
+        })
+        .unzip()
+}
+
+/// Describes which fields a new builder has set, for the documentation of
+/// the methods creating one.
+fn describe_required_args(fields: &[ParsedField]) -> String {
+    let names: Vec<String> = fields
+        .iter()
+        .filter(|field| field.requiredarg)
+        .map(|field| format!("`{}`", field.display_name()))
+        .collect();
+    match names.as_slice() {
+        [] => "no fields set".to_owned(),
+        [name] => format!("{} set to the given value", name),
+        [init @ .., last] => format!("{} and {} set to the given values", init.join(", "), last),
+    }
+}
+
+fn generate_builder_struct(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let structattributes = &parsed.attributes;
+
+    let fields = &parsed.fields;
+    // A derived `Debug` would print secret fields, so it is replaced by an
+    // implementation that redacts them.
+    let isdebug = |path: &Path| path.segments.last().is_some_and(|it| it.ident == "Debug");
+    let hassecret = fields.iter().any(|field| field.secret);
+    if hassecret {
+        if let Some(debug) = structattributes
+            .structattrs
+            .iter()
+            .find_map(|meta| find_forwarded_derive(meta, isdebug))
+        {
+            return Err(syn::Error::new_spanned(
+                debug,
+                "`Debug` derived through `struct_attr` would print secret fields; \
+                 use `#[builder(derive(Debug))]`, which redacts them",
+            ));
+        }
+    }
+    if structattributes.tracksource {
+        check_source_derives(parsed)?;
+    }
+    let redactdebug = hassecret && structattributes.derives.iter().any(isdebug);
+    let derives: Vec<&Path> = structattributes
+        .derives
+        .iter()
+        .filter(|path| !(redactdebug && isdebug(path)))
+        .collect();
+    let derive = if derives.is_empty() {
+        quote! {}
+    } else {
+        quote! { #[derive(#(#derives),*)] }
+    };
+    let debugimpl = if redactdebug {
+        generate_builder_debug_impl(parsed)
+    } else {
+        TokenStream::new()
+    };
+    let structattrs = &structattributes.structattrs;
+
+    let fields = fields.iter().map(|field| {
+        let ty = field.setter_ty();
+        let ty = if field.requiredarg {
+            let ty = field.ty();
+            quote! { #ty }
+        } else if field.has_custom_storage_type() {
+            quote! { #ty }
+        } else {
+            quote! { ::std::option::Option<#ty> }
+        };
+        let name = field.name();
+        let fieldattrs = &field.fieldattrs;
+        let cfgattrs = &field.cfgattrs;
+        quote! {
+            #(#cfgattrs)*
+            #(#[#fieldattrs])*
+            #name: #ty
+        }
+    });
+
+    let doc = format!(
+        "A builder for [`{0}`].\n\nCreate one with [`{0}::builder`], call the setters for the \
+         fields you want to set and finish with [`{1}::build`].",
+        inputtree.ident, builderstructname
+    );
+
+    let sourcefields = if structattributes.tracksource {
+        let sourcename = get_builder_source_name(inputtree);
+        let (sources, label) = get_source_field_names();
+        let count = Literal::usize_unsuffixed(parsed.fields.len());
+        quote! {
+            #sources: [::std::option::Option<#sourcename>; #count],
+            #label: ::std::option::Option<::std::string::String>,
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let (impl_generics, _, where_clause) = inputtree.generics.split_for_impl();
+    let output = quote! {
+        #[doc = #doc]
+        #derive
+        #(#[#structattrs])*
+        pub struct #builderstructname #impl_generics #where_clause {
+            #(#fields,)*
+            #sourcefields
+        }
+
+        #debugimpl
+    };
+    Ok(output)
+}
+
+/// The traits the builder may derive with `track_source`, which
+/// `<Builder>Source` implements.
+const SOURCE_DERIVES: &[&str] = &[
+    "Debug",
+    "Clone",
+    "PartialEq",
+    "Eq",
+    "Hash",
+    "PartialOrd",
+    "Ord",
+    "Default",
+];
+
+/// Checks that the sources of the fields recorded by `track_source` support
+/// the traits derived for the builder.
+fn check_source_derives(parsed: &ParsedStruct) -> Result<(), syn::Error> {
+    let structattributes = &parsed.attributes;
+    let name = |path: &Path| path.segments.last().map(|it| it.ident.to_string());
+    let unsupported = |path: &Path| {
+        // The sources are held in an array, which is only `Default` up to 32
+        // elements.
+        let name = name(path);
+        !SOURCE_DERIVES.contains(&name.as_deref().unwrap_or_default())
+            || (name.as_deref() == Some("Default") && parsed.fields.len() > 32)
+    };
+    let derive = structattributes
+        .derives
+        .iter()
+        .find(|path| unsupported(path))
+        .or_else(|| {
+            structattributes
+                .structattrs
+                .iter()
+                .find_map(|meta| find_forwarded_derive(meta, unsupported))
+        });
+    match derive {
+        Some(derive) if name(derive).as_deref() == Some("Default") => Err(syn::Error::new_spanned(
+            derive,
+            "`track_source` supports deriving `Default` only for structs with at most 32 fields",
+        )),
+        Some(derive) => Err(syn::Error::new_spanned(
+            derive,
+            format!(
+                "`track_source` supports deriving only `{}` for the builder",
+                SOURCE_DERIVES.join("`, `")
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Finds a trait matching `pred` in a `derive(...)` forwarded with
+/// `struct_attr`, including one behind `cfg_attr`.
+fn find_forwarded_derive(meta: &Meta, pred: impl Fn(&Path) -> bool + Copy) -> Option<&Path> {
+    let metalist = match meta {
+        Meta::List(metalist) => metalist,
+        _ => return None,
+    };
+    if metalist.path.is_ident("derive") {
+        metalist.nested.iter().find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) if pred(path) => Some(path),
+            _ => None,
+        })
+    } else if metalist.path.is_ident("cfg_attr") {
+        metalist
+            .nested
+            .iter()
+            .skip(1)
+            .find_map(|nested| match nested {
+                NestedMeta::Meta(meta) => find_forwarded_derive(meta, pred),
+                _ => None,
+            })
+    } else {
+        None
+    }
+}
+
+/// `Debug` for a builder with `secret` fields, which shows `"<redacted>"` in
+/// place of their values. Like a derived implementation, it requires every
+/// type parameter to implement `Debug`.
+fn generate_builder_debug_impl(parsed: &ParsedStruct) -> TokenStream {
+    let inputtree = parsed.inputtree;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let mut generics = inputtree.generics.clone();
+    let typeparams: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let whereclause = generics.make_where_clause();
+    for param in typeparams {
+        whereclause
+            .predicates
+            .push(syn::parse_quote!(#param: ::std::fmt::Debug));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let formatter = Ident::new("f", Span::mixed_site());
+    let debug = Ident::new("debug", Span::mixed_site());
+    let fields = parsed.fields.iter().map(|field| {
+        let name = field.name();
+        let displayname = field.display_name();
+        let cfgattrs = &field.cfgattrs;
+        let value = if field.secret {
+            quote! { &"<redacted>" }
+        } else {
+            quote! { &self.#name }
+        };
+        quote! {
+            #(#cfgattrs)*
+            #debug.field(#displayname, #value);
+        }
+    });
+    let structname = builderstructname.to_string();
+    quote! {
+        impl #impl_generics ::std::fmt::Debug for #builderstructname #ty_generics #where_clause {
+            fn fmt(&self, #formatter: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let mut #debug = #formatter.debug_struct(#structname);
+                #(#fields)*
+                #debug.finish()
+            }
+        }
+    }
+}
+
+fn generate_builder_error(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let buildererrorname = get_builder_error_name(inputtree);
+    let doc = format!("An error returned by [`{}::build`].", builderstructname);
+    let formatter = Ident::new("f", Span::mixed_site());
+    let tracksource = parsed.attributes.tracksource;
+    let sourcecode = if tracksource {
+        generate_builder_source(inputtree)
+    } else {
+        TokenStream::new()
+    };
+    let (sourcefield, invalidfield) = if tracksource {
+        let sourcename = get_builder_source_name(inputtree);
+        (
+            quote! {
+                /// Where the rejected value came from.
+                source: ::std::option::Option<#sourcename>,
+            },
+            quote! {
+                Self::InvalidField { field, message, source: ::std::option::Option::Some(source), .. } => {
+                    ::std::write!(#formatter, "invalid {} (from {}): {}", field, source, message)
+                }
+            },
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+    let output = quote! {
+        #[doc = #doc]
+        #[derive(::std::fmt::Debug)]
+        pub enum #buildererrorname {
+            /// A required field has not been set.
+            MissingField {
+                /// The name of the field.
+                field: &'static str,
+            },
+            /// The value of a field violates one of its constraints.
+            InvalidField {
+                /// The name of the field.
+                field: &'static str,
+                /// The violated constraint, as written in the `#[builder]` attribute.
+                constraint: &'static str,
+                /// Why the value was rejected.
+                message: ::std::string::String,
+                #sourcefield
+            },
+            /// None of the fields of a `one_of` constraint has been set.
+            NoneSet {
+                /// The fields of the constraint.
+                fields: &'static [&'static str],
+            },
+            /// More than one of the fields of a `one_of` or `conflicts`
+            /// constraint has been set.
+            ConflictingFields {
+                /// The fields of the constraint.
+                fields: &'static [&'static str],
+            },
+            /// A field has been set without a field it `requires`.
+            MissingDependency {
+                /// The field that has been set.
+                field: &'static str,
+                /// The field it requires, which has not been set.
+                requires: &'static str,
+            },
+            /// An error returned by the `build_fn(validate)` function, or by
+            /// a `?` in a `field(build)` expression.
+            Custom(::std::boxed::Box<dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync>),
+        }
+
+        impl ::std::fmt::Display for #buildererrorname {
+            fn fmt(&self, #formatter: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    Self::MissingField { field } => {
+                        ::std::write!(#formatter, "{} must be set", field)
+                    }
+                    #invalidfield
+                    Self::InvalidField { field, message, .. } => {
+                        ::std::write!(#formatter, "invalid {}: {}", field, message)
+                    }
+                    Self::NoneSet { fields } => {
+                        ::std::write!(#formatter, "one of {} must be set", fields.join(", "))
+                    }
+                    Self::ConflictingFields { fields } => {
+                        ::std::write!(#formatter, "only one of {} can be set", fields.join(", "))
+                    }
+                    Self::MissingDependency { field, requires } => {
+                        ::std::write!(#formatter, "{} requires {} to be set", field, requires)
+                    }
+                    Self::Custom(err) => ::std::fmt::Display::fmt(err, #formatter),
+                }
+            }
+        }
+
+        impl ::std::error::Error for #buildererrorname {}
+
+        #sourcecode
+    };
+    Ok(output)
+}
+
+fn generate_builder_source(inputtree: &DeriveInput) -> TokenStream {
+    let structname = &inputtree.ident;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let sourcename = get_builder_source_name(inputtree);
+    let doc = format!(
+        "Where the value of a field of a [`{0}`] came from, see [`{0}::source_of`].",
+        builderstructname
+    );
+    let argumentdoc = format!("Given to [`{}::builder`] as a `required_arg`.", structname);
+    let setterdoc = format!(
+        "Set by the method `method`, while the label set with [`{}::source_label`] \
+         was `label`.",
+        builderstructname
+    );
+    let formatter = Ident::new("f", Span::mixed_site());
+    quote! {
+        #[doc = #doc]
+        #[derive(
+            ::std::fmt::Debug,
+            ::std::clone::Clone,
+            ::std::cmp::PartialEq,
+            ::std::cmp::Eq,
+            ::std::hash::Hash,
+            ::std::cmp::PartialOrd,
+            ::std::cmp::Ord,
+        )]
+        pub enum #sourcename {
+            #[doc = #argumentdoc]
+            Argument,
+            #[doc = #setterdoc]
+            Setter {
+                /// The name of the method that set the value: a setter, an
+                /// element setter, an alias or `parse_args`.
+                method: &'static str,
+                /// The label describing where the setter's value came from.
+                label: ::std::option::Option<::std::string::String>,
+            },
+        }
+
+        impl ::std::fmt::Display for #sourcename {
+            fn fmt(&self, #formatter: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    Self::Argument => ::std::fmt::Formatter::write_str(#formatter, "builder argument"),
+                    Self::Setter { label: ::std::option::Option::Some(label), .. } => {
+                        ::std::fmt::Formatter::write_str(#formatter, label)
+                    }
+                    Self::Setter { method, label: ::std::option::Option::None } => {
+                        ::std::write!(#formatter, "{}()", method)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generates the type of the entries of `<Builder>::FIELDS`, which describe
+/// the fields of the struct at runtime, e.g. to render help or documentation
+/// for a configuration.
+fn generate_builder_field_info(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let fieldinfoname = get_builder_field_info_name(inputtree);
+    let doc = format!(
+        "Describes a field set by a [`{0}`], see [`{0}::FIELDS`].",
+        builderstructname
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(
+            ::std::fmt::Debug,
+            ::std::clone::Clone,
+            ::std::marker::Copy,
+            ::std::cmp::PartialEq,
+            ::std::cmp::Eq,
+        )]
+        pub struct #fieldinfoname {
+            /// The name of the field.
+            pub name: &'static str,
+            /// The type of the field, as written in the struct.
+            pub ty: &'static str,
+            /// Whether the field must be given for the build to succeed.
+            pub required: bool,
+            /// The value taken by the field when it is not set, as written in
+            /// its `default` option. It is left out for a `secret` field.
+            pub default: ::std::option::Option<&'static str>,
+            /// The name of the field's element setter, from its `each` option.
+            pub each: ::std::option::Option<&'static str>,
+            /// The documentation of the field.
+            pub docs: &'static str,
+        }
+    })
+}
+
+/// Generates `<Builder>::FIELDS`, describing the fields of the struct in
+/// declaration order.
+fn generate_builder_impl_fields_const(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let fieldinfoname = get_builder_field_info_name(inputtree);
+    let entries = parsed.fields.iter().map(|field| {
+        let name = field.display_name();
+        let ty = get_type_text(field.ty());
+        let required = field.is_required();
+        let default = match &field.defaultattr {
+            Some(default) if !field.secret => {
+                let text = &default.text;
+                quote! { ::std::option::Option::Some(#text) }
+            }
+            _ => quote! { ::std::option::Option::None },
+        };
+        let each = match &field.vecattr {
+            Some(vecattr) => {
+                let method = vecattr.method.unraw().to_string();
+                quote! { ::std::option::Option::Some(#method) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
+        let docs = get_doc_text(&field.docs);
+        let cfgattrs = &field.cfgattrs;
+        quote! {
+            #(#cfgattrs)*
+            #fieldinfoname {
+                name: #name,
+                ty: #ty,
+                required: #required,
+                default: #default,
+                each: #each,
+                docs: #docs,
+            }
+        }
+    });
+    let doc = format!(
+        "Describes the fields of [`{}`], in the order they are declared.",
+        inputtree.ident
+    );
+    Ok(quote! {
+        #[doc = #doc]
+        pub const FIELDS: &'static [#fieldinfoname] = &[#(#entries),*];
+    })
+}
+
+fn generate_builder_impl(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let fieldsconst = generate_builder_impl_fields_const(parsed)?;
+    let newmethod = generate_builder_impl_new_method(parsed)?;
+    let fieldsetters = generate_builder_impl_field_setters(parsed)?;
+    let fieldelementsetters = generate_builder_impl_field_element_setters(parsed)?;
+    let buildmethod = generate_builder_impl_build_method(parsed)?;
+    let sourcemethods = generate_builder_impl_source_methods(parsed)?;
+    let (impl_generics, ty_generics, where_clause) = inputtree.generics.split_for_impl();
+
+    let output = quote! {
+        impl #impl_generics #builderstructname #ty_generics #where_clause {
+            #fieldsconst
+
+            #newmethod
+
+            #(#fieldsetters)*
+
+            #(#fieldelementsetters)*
+
+            #sourcemethods
+
+            #buildmethod
+        }
+    };
+    Ok(output)
+}
+
+fn generate_builder_impl_source_methods(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    if !parsed.attributes.tracksource {
+        return Ok(TokenStream::new());
+    }
+    let sourcename = get_builder_source_name(parsed.inputtree);
+    let (sources, label) = get_source_field_names();
+    let arms = parsed.fields.iter().enumerate().map(|(index, field)| {
+        let position = Literal::usize_unsuffixed(index);
+        let name = field.display_name();
+        let cfgattrs = &field.cfgattrs;
+        quote! {
+            #(#cfgattrs)*
+            #name => ::std::option::Option::as_ref(&self.#sources[#position]),
+        }
+    });
+    let field = Ident::new("field", Span::mixed_site());
+    let newlabel = Ident::new("label", Span::mixed_site());
+    Ok(quote! {
+        /// Returns where the value of the field named `field` came from, or
+        /// `None` if the field has not been set, or has no such name.
+        pub fn source_of(&self, #field: &str) -> ::std::option::Option<&#sourcename> {
+            match #field {
+                #(#arms)*
+                _ => ::std::option::Option::None,
+            }
+        }
+
+        /// Sets the label recorded as the source of the fields set from now
+        /// on, e.g. the name of a configuration file.
+        pub fn source_label(
+            &mut self,
+            #newlabel: impl ::std::convert::Into<::std::string::String>,
+        ) -> &mut Self {
+            self.#label = ::std::option::Option::Some(::std::convert::Into::into(#newlabel));
+            self
+        }
+    })
+}
+
+fn generate_builder_impl_field_setters(
+    parsed: &ParsedStruct,
+) -> Result<Vec<TokenStream>, syn::Error> {
+    let vis = &parsed.inputtree.vis;
+    let constbuilder = parsed.attributes.constbuilder;
+    let fieldsetters = parsed
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.should_have_set_method())
+        .map(|(index, field)| {
+            let ty = field.setter_arg_ty();
+            let name = field.name();
+            let setter = field.setter_name();
+            let arg = field.setter_arg_value(&setter);
+            let value = if field.has_custom_storage_type() {
+                quote! { #arg }
+            } else {
+                quote! { ::std::option::Option::Some(#arg) }
+            };
+            let setterattrs = &field.setterattrs;
+            let cfgattrs = &field.cfgattrs;
+            let docs = if field.docs.is_empty() {
+                let doc = format!("Sets `{}`.", field.display_name());
+                quote! { #[doc = #doc] }
+            } else {
+                let docs = &field.docs;
+                quote! { #(#docs)* }
+            };
+            let receiver = if constbuilder {
+                quote! { const fn #setter(mut self, #setter: #ty) -> Self }
+            } else {
+                quote! { fn #setter(&mut self, #setter: #ty) -> &mut Self }
+            };
+            let sourceupdate = generate_source_update(parsed, index, &setter);
+            let aliases = generate_builder_impl_alias_setters(parsed, index, field);
+            quote! {
+                    #docs
+                    #(#cfgattrs)*
+                    #(#[#setterattrs])*
+                    #vis #receiver {
+                        self.#name = #value;
+                        #sourceupdate
+                        self
+                    }
+
+                    #(#aliases)*
+            }
+        })
+        .collect();
+
+    Ok(fieldsetters)
+}
+
+/// Generates the setters named after the `alias` options of a field, which
+/// are deprecated and forward to the field's setter.
+fn generate_builder_impl_alias_setters(
+    parsed: &ParsedStruct,
+    index: usize,
+    field: &ParsedField,
+) -> Vec<TokenStream> {
+    let vis = &parsed.inputtree.vis;
+    let ty = field.setter_arg_ty();
+    let setter = field.setter_name();
+    let cfgattrs = &field.cfgattrs;
+    let note = match &field.deprecated {
+        Some(note) => note.value(),
+        None => format!("renamed to `{}`", field.display_name()),
+    };
+    let doc = format!(
+        "Deprecated alias of [`{}`](Self::{}).",
+        field.display_name(),
+        setter
+    );
+    field
+        .aliases
+        .iter()
+        .map(|alias| {
+            let receiver = if parsed.attributes.constbuilder {
+                quote! { const fn #alias(self, #setter: #ty) -> Self }
+            } else {
+                quote! { fn #alias(&mut self, #setter: #ty) -> &mut Self }
+            };
+            // The setter records itself as the source, which the alias then
+            // replaces.
+            let body = if parsed.attributes.tracksource {
+                let sourceupdate = generate_source_update(parsed, index, alias);
+                quote! {
+                    self.#setter(#setter);
+                    #sourceupdate
+                    self
+                }
+            } else {
+                quote! { self.#setter(#setter) }
+            };
+            quote! {
+                #[doc = #doc]
+                #[deprecated(note = #note)]
+                #(#cfgattrs)*
+                #vis #receiver {
+                    #body
+                }
+            }
+        })
+        .collect()
+}
+
+fn generate_builder_impl_field_element_setters(
+    parsed: &ParsedStruct,
+) -> Result<Vec<TokenStream>, syn::Error> {
+    let vis = &parsed.inputtree.vis;
+    let fieldelementsetters = parsed
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, pf)| pf.vecattr.is_some())
+        .map(|(index, pf)| {
+            let ty = get_vec_type_inner(&pf.field.ty);
+            let methodname = pf.vecattr.clone().unwrap().method;
+            let fieldname = pf.field.ident.clone().unwrap();
+            let setterattrs = &pf.setterattrs;
+            let cfgattrs = &pf.cfgattrs;
+            let doc = format!("Appends a single element to `{}`.", pf.display_name());
+            let docs = &pf.docs;
+            let separator = if docs.is_empty() {
+                quote! {}
+            } else {
+                quote! { #[doc = ""] }
+            };
+            let sourceupdate = generate_source_update(parsed, index, &methodname);
+            quote! {
+                    #[doc = #doc]
+                    #separator
+                    #(#docs)*
+                    #(#cfgattrs)*
+                    #(#[#setterattrs])*
+                    #vis fn #methodname(&mut self, #methodname: #ty) -> &mut Self {
+
+                        self.#fieldname
+                        .get_or_insert_with(|| ::std::vec::Vec::new())
+                        .push(#methodname);
+                        #sourceupdate
+                        self
+                    }
+            }
+        })
+        .collect();
+
+    Ok(fieldelementsetters)
+}
+
+fn generate_builder_impl_build_method(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let structattributes = &parsed.attributes;
+    if structattributes.constbuilder {
+        return generate_builder_impl_const_build_method(parsed);
+    }
+    let inputtree = parsed.inputtree;
+    let buildererrorname = get_builder_error_name(inputtree);
+    let fields = &parsed.fields;
+    let fieldnames = fields.iter().map(|field| {
+        let name = field.name();
+        let local = field.local_name();
+        let cfgattrs = &field.cfgattrs;
+        quote! { #(#cfgattrs)* #name: #local }
+    });
+    let checkforunset = fields.iter().enumerate().map(|(index, field)| {
+        let name = field.name();
+        let local = field.local_name();
+        let inner = Ident::new("inner", Span::mixed_site());
+        let cfgattrs = &field.cfgattrs;
+        let source = structattributes.tracksource.then(|| {
+            let (sources, _) = get_source_field_names();
+            let position = Literal::usize_unsuffixed(index);
+            quote! { ::std::clone::Clone::clone(&self.#sources[#position]) }
+        });
+        let checks = generate_constraint_checks(field, &buildererrorname, source);
+        let value = if field.requiredarg {
+            quote! {
+                #(#cfgattrs)*
+                let #local = ::std::clone::Clone::clone(&self.#name);
+            }
+        } else if let Some(build) = field.build_expr() {
+            // The expression may use `?` on any error, which becomes `Custom`.
+            let ok = quote! {
+                ::std::result::Result::<_, ::std::boxed::Box<
+                    dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync
+                >>::Ok(#build)
+            };
+            let result = if structattributes.asyncbuild {
+                quote! { async { #ok }.await }
+            } else {
+                quote! { (|| #ok)() }
+            };
+            let err = Ident::new("err", Span::mixed_site());
+            quote! {
+                #(#cfgattrs)*
+                let #local = match #result {
+                    ::std::result::Result::Ok(#inner) => #inner,
+                    ::std::result::Result::Err(#err) => {
+                        return ::std::result::Result::Err(#buildererrorname::Custom(#err))
+                    }
+                };
+            }
+        } else if field.has_custom_storage_type() {
+            quote! {
+                #(#cfgattrs)*
+                let #local = ::std::convert::Into::into(::std::clone::Clone::clone(&self.#name));
+            }
+        } else if let Some(default) = &field.defaultattr {
+            let default = default.value();
+            let value = if is_option_type(field.ty()) {
+                quote! { ::std::option::Option::Some(::std::clone::Clone::clone(#inner)) }
+            } else {
+                quote! { ::std::clone::Clone::clone(#inner) }
+            };
+            quote! {
+                #(#cfgattrs)*
+                let #local = match &self.#name {
+                    ::std::option::Option::Some(#inner) => #value,
+                    ::std::option::Option::None => #default,
+                };
+            }
+        } else if is_option_type(field.ty()) {
+            quote! {
+                #(#cfgattrs)*
+                let #local = ::std::clone::Clone::clone(&self.#name);
+            }
+        } else {
+            let fieldname = field.display_name();
+            quote! {
+                #(#cfgattrs)*
+                let #local = match &self.#name {
+                    ::std::option::Option::Some(#inner) => ::std::clone::Clone::clone(#inner),
+                    ::std::option::Option::None => {
+                        return ::std::result::Result::Err(::std::convert::Into::into(
+                            #buildererrorname::MissingField { field: #fieldname },
+                        ))
+                    }
+                };
+            }
+        };
+        quote! {
+            #value
+            #checks
+        }
+    });
+    let structname = &inputtree.ident;
+    let (_, ty_generics, _) = inputtree.generics.split_for_impl();
+    let doc = generate_build_method_doc(parsed);
+    let (asyncness, awaitcall) = if structattributes.asyncbuild {
+        (quote! { async }, quote! { .await })
+    } else {
+        (quote! {}, quote! {})
+    };
+    let value = Ident::new("value", Span::mixed_site());
+    let validate = structattributes.validate.as_ref().map(|validate| {
+        let err = Ident::new("err", Span::mixed_site());
+        quote! {
+            if let ::std::result::Result::Err(#err) = #validate(&#value) #awaitcall {
+                return ::std::result::Result::Err(#buildererrorname::Custom(
+                    ::std::convert::Into::into(#err),
+                ));
+            }
+        }
+    });
+    let relationchecks = generate_relation_checks(structattributes, fields, &buildererrorname);
+    let buildmethod = quote! {
+        #(#[doc = #doc])*
+        pub #asyncness fn build(&mut self) -> ::std::result::Result<#structname #ty_generics, #buildererrorname> {
+            #(#relationchecks)*
+            #(#checkforunset)*
+            let #value = #structname {
+                #(#fieldnames),*
+            };
+            #validate
+            ::std::result::Result::Ok(#value)
+        }
+    };
+
+    Ok(buildmethod)
+}
+
+/// Generates the checks of the struct's `one_of`, `requires` and `conflicts`
+/// constraints, which look at which fields have been set on the builder.
+fn generate_relation_checks(
+    structattributes: &ParsedStructAttributes,
+    fields: &[ParsedField],
+    buildererrorname: &Ident,
+) -> Vec<TokenStream> {
+    let isset = |name: &Ident| {
+        let field = fields
+            .iter()
+            .find(|field| field.name().unraw() == name.unraw())
+            .expect("relations only name existing fields");
+        let name = field.name();
+        if field.has_vec_attribute() {
+            let inner = Ident::new("inner", Span::mixed_site());
+            quote! {
+                ::std::matches!(&self.#name, ::std::option::Option::Some(#inner) if !#inner.is_empty())
+            }
+        } else {
+            quote! { ::std::option::Option::is_some(&self.#name) }
+        }
+    };
+    let fail = |variant: TokenStream| {
+        quote! {
+            return ::std::result::Result::Err(::std::convert::Into::into(
+                #buildererrorname::#variant,
+            ))
+        }
+    };
+    structattributes
+        .relations
+        .iter()
+        .map(|relation| {
+            let names: Vec<String> = relation
+                .fields()
+                .iter()
+                .map(|name| name.unraw().to_string())
+                .collect();
+            let count = relation.fields().iter().map(|name| {
+                let isset = isset(name);
+                quote! { ::std::primitive::usize::from(#isset) }
+            });
+            match relation {
+                ParsedFieldRelation::OneOf(_) => {
+                    let noneset = fail(quote! { NoneSet { fields: &[#(#names),*] } });
+                    let conflicting = fail(quote! { ConflictingFields { fields: &[#(#names),*] } });
+                    quote! {
+                        match #(#count)+* {
+                            0 => #noneset,
+                            1 => {}
+                            _ => #conflicting,
+                        }
+                    }
+                }
+                ParsedFieldRelation::Conflicts(_) => {
+                    let conflicting = fail(quote! { ConflictingFields { fields: &[#(#names),*] } });
+                    quote! {
+                        if #(#count)+* > 1 {
+                            #conflicting
+                        }
+                    }
+                }
+                ParsedFieldRelation::Requires(fields) => {
+                    let field = &names[0];
+                    let first = isset(&fields[0]);
+                    let requirements =
+                        fields[1..].iter().zip(&names[1..]).map(|(name, requires)| {
+                            let isset = isset(name);
+                            let missing = fail(quote! {
+                                MissingDependency { field: #field, requires: #requires }
+                            });
+                            quote! {
+                                if !#isset {
+                                    #missing
+                                }
+                            }
+                        });
+                    quote! {
+                        if #first {
+                            #(#requirements)*
+                        }
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+/// Generates the checks of a field's constraints, run on the field's value in
+/// the build method. The constraints of an `Option` field apply to the value
+/// it holds, if any.
+/// With `track_source`, `source` is the source recorded for the field.
+fn generate_constraint_checks(
+    field: &ParsedField,
+    buildererrorname: &Ident,
+    source: Option<TokenStream>,
+) -> TokenStream {
+    if field.constraints.is_empty() {
+        return TokenStream::new();
+    }
+    let local = field.local_name();
+    let inner = Ident::new("inner", Span::mixed_site());
+    let cfgattrs = &field.cfgattrs;
+    let fieldname = field.display_name();
+    let sourcefield = source.map(|source| quote! { source: #source, });
+    let checks = field.constraints.iter().map(|constraint| {
+        let text = constraint.text();
+        let invalid = |message: TokenStream| {
+            quote! {
+                return ::std::result::Result::Err(::std::convert::Into::into(
+                    #buildererrorname::InvalidField {
+                        field: #fieldname,
+                        constraint: #text,
+                        message: #message,
+                        #sourcefield
+                    },
+                ))
+            }
+        };
+        match constraint {
+            ParsedConstraint::Range { min, max, .. } => {
+                let min = min.as_ref().map(|min| {
+                    let message = format!("must be at least {}", min.text);
+                    let invalid = invalid(quote! { ::std::string::String::from(#message) });
+                    let min = &min.expr;
+                    quote! { if *#inner < #min { #invalid } }
+                });
+                let max = max.as_ref().map(|max| {
+                    let message = format!("must be at most {}", max.text);
+                    let invalid = invalid(quote! { ::std::string::String::from(#message) });
+                    let max = &max.expr;
+                    quote! { if *#inner > #max { #invalid } }
+                });
+                quote! { #min #max }
+            }
+            ParsedConstraint::NonEmpty(_) => {
+                let invalid = invalid(quote! { ::std::string::String::from("must not be empty") });
+                quote! { if #inner.is_empty() { #invalid } }
+            }
+            ParsedConstraint::Regex(pattern) => {
+                let message = format!("must match `{}`", pattern.value());
+                let invalid = invalid(quote! { ::std::string::String::from(#message) });
+                let compiled = Ident::new("PATTERN", Span::mixed_site());
+                let regex = Ident::new("regex", Span::mixed_site());
+                quote! {
+                    {
+                        static #compiled: ::std::sync::OnceLock<::regex::Regex> =
+                            ::std::sync::OnceLock::new();
+                        let #regex = #compiled.get_or_init(|| {
+                            ::regex::Regex::new(#pattern).expect("the pattern is checked by the derive")
+                        });
+                        if !#regex.is_match(::std::convert::AsRef::<str>::as_ref(#inner)) {
+                            #invalid
+                        }
+                    }
+                }
+            }
+            ParsedConstraint::Validate(path) => {
+                let err = Ident::new("err", Span::mixed_site());
+                // The validator's message may quote the value, which must not
+                // end up in errors for a secret field.
+                let (pattern, message) = if field.secret {
+                    let message = format!(
+                        "rejected by `{}`",
+                        quote!(#path).to_string().replace(' ', "")
+                    );
+                    (quote! { _ }, quote! { ::std::string::String::from(#message) })
+                } else {
+                    (
+                        quote! { #err },
+                        quote! { ::std::string::ToString::to_string(&#err) },
+                    )
+                };
+                let invalid = invalid(message);
+                quote! {
+                    if let ::std::result::Result::Err(#pattern) = #path(#inner) {
+                        #invalid
+                    }
+                }
+            }
+        }
+    });
+    if is_option_type(field.ty()) {
+        quote! {
+            #(#cfgattrs)*
+            if let ::std::option::Option::Some(#inner) = &#local {
+                #(#checks)*
+            }
+        }
+    } else {
+        quote! {
+            #(#cfgattrs)*
+            {
+                let #inner = &#local;
+                #(#checks)*
+            }
+        }
+    }
+}
+
+/// `const fn` version of the build method. It consumes the builder, since
+/// `Clone::clone` cannot be called in a `const fn`, and reports missing
+/// fields as a `&'static str` which can be passed to `panic!` at compile time.
+fn generate_builder_impl_const_build_method(
+    parsed: &ParsedStruct,
+) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let fields = &parsed.fields;
+    let fieldnames = fields.iter().map(|field| {
+        let name = field.name();
+        let local = field.local_name();
+        let cfgattrs = &field.cfgattrs;
+        quote! { #(#cfgattrs)* #name: #local }
+    });
+    let checkforunset = fields.iter().map(|field| {
+        let name = field.name();
+        let local = field.local_name();
+        let inner = Ident::new("inner", Span::mixed_site());
+        let cfgattrs = &field.cfgattrs;
+        if field.requiredarg {
+            quote! {
+                #(#cfgattrs)*
+                let #local = self.#name;
+            }
+        } else if let Some(default) = &field.defaultattr {
+            let default = &default.expr;
+            let value = if is_option_type(field.ty()) {
+                quote! { ::std::option::Option::Some(#inner) }
+            } else {
+                quote! { #inner }
+            };
+            quote! {
+                #(#cfgattrs)*
+                let #local = match self.#name {
+                    ::std::option::Option::Some(#inner) => #value,
+                    ::std::option::Option::None => #default,
+                };
+            }
+        } else if is_option_type(field.ty()) {
+            quote! {
+                #(#cfgattrs)*
+                let #local = self.#name;
+            }
+        } else {
+            let msg = format!("{} must be set", field.display_name());
+            quote! {
+                #(#cfgattrs)*
+                let #local = match self.#name {
+                    ::std::option::Option::Some(#inner) => #inner,
+                    ::std::option::Option::None => return ::std::result::Result::Err(#msg),
+                };
+            }
+        }
+    });
+    let structname = &inputtree.ident;
+    let (_, ty_generics, _) = inputtree.generics.split_for_impl();
+    let doc = generate_build_method_doc(parsed);
+    let buildmethod = quote! {
+        #(#[doc = #doc])*
+        pub const fn build(self) -> ::std::result::Result<#structname #ty_generics, &'static str> {
+            #(#checkforunset)*
+            ::std::result::Result::Ok(
+                #structname {
+                    #(#fieldnames),*
+                }
+            )
+        }
+    };
+
+    Ok(buildmethod)
+}
+
+fn generate_build_method_doc(parsed: &ParsedStruct) -> Vec<String> {
+    let mut required = Vec::new();
+    let mut defaults = Vec::new();
+    let mut computed = Vec::new();
+    let mut constraints = Vec::new();
+    for field in &parsed.fields {
+        for constraint in &field.constraints {
+            constraints.push(format!(
+                "- `{}`: `{}`",
+                field.display_name(),
+                constraint.text()
+            ));
+        }
+        if let Some(build) = field
+            .storageattr
+            .as_ref()
+            .and_then(|it| it.buildtext.as_ref())
+        {
+            computed.push(format!("- `{}`: `{}`", field.display_name(), build));
+        } else if field.has_custom_storage_type() {
+            computed.push(format!(
+                "- `{}`: converted with `Into` from the value stored in the builder",
+                field.display_name()
+            ));
+        } else if let Some(default) = &field.defaultattr {
+            if field.secret {
+                defaults.push(format!("- `{}`: a default", field.display_name()));
+            } else {
+                defaults.push(format!("- `{}`: `{}`", field.display_name(), default.text));
+            }
+        } else if field.has_vec_attribute() {
+            defaults.push(format!("- `{}`: an empty `Vec`", field.display_name()));
+        } else if is_option_type(field.ty()) {
+            defaults.push(format!("- `{}`: `None`", field.display_name()));
+        } else {
+            required.push(format!("- `{}`", field.display_name()));
+        }
+    }
+
+    let mut doc = vec![format!(
+        "Builds a new [`{}`] from the values set on this builder.",
+        parsed.inputtree.ident
+    )];
+    let hasrequired = !required.is_empty();
+    let mut haserrors = hasrequired;
+    if hasrequired {
+        doc.push(String::new());
+        doc.push("# Errors".to_owned());
+        doc.push(String::new());
+        doc.push("Returns an error if any of these required fields has not been set:".to_owned());
+        doc.push(String::new());
+        doc.extend(required);
+    }
+    if let Some(validate) = &parsed.attributes.validate {
+        if !haserrors {
+            doc.push(String::new());
+            doc.push("# Errors".to_owned());
+            haserrors = true;
+        }
+        doc.push(String::new());
+        doc.push(format!(
+            "Returns an error if `{}` rejects the built value.",
+            quote!(#validate).to_string().replace(' ', "")
+        ));
+    }
+    let relations = &parsed.attributes.relations;
+    if !relations.is_empty() {
+        if !haserrors {
+            doc.push(String::new());
+            doc.push("# Errors".to_owned());
+            haserrors = true;
+        }
+        doc.push(String::new());
+        doc.push("Returns an error if the fields that have been set violate any of:".to_owned());
+        doc.push(String::new());
+        doc.extend(
+            relations
+                .iter()
+                .map(|relation| format!("- {}", relation.describe())),
+        );
+    }
+    if !constraints.is_empty() {
+        if !haserrors {
+            doc.push(String::new());
+            doc.push("# Errors".to_owned());
+        }
+        doc.push(String::new());
+        doc.push("Returns an error if the value of a field violates its constraints:".to_owned());
+        doc.push(String::new());
+        doc.extend(constraints);
+    }
+    if !defaults.is_empty() {
+        doc.push(String::new());
+        doc.push("Fields that are not set take these defaults:".to_owned());
+        doc.push(String::new());
+        doc.extend(defaults);
+    }
+    if !computed.is_empty() {
+        doc.push(String::new());
+        doc.push("These fields are computed from the builder when it is built:".to_owned());
+        doc.push(String::new());
+        doc.extend(computed);
+    }
+    doc
+}
+
+/// Generates `<Builder>::parse_args`, which fills a builder from command-line
+/// arguments by calling its setters, `<Builder>::help` describing the
+/// arguments, and the `<Builder>ArgsError` type for arguments that cannot be
+/// parsed.
+///
+/// Every field with a setter or an element setter becomes an option named
+/// after the setter, with underscores replaced by dashes. Values are parsed
+/// with `FromStr`, except that a `bool` option may be given without a value.
+fn generate_cli(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let argserrorname = format_ident!("{}BuilderArgsError", inputtree.ident);
+    let (impl_generics, ty_generics, where_clause) = inputtree.generics.split_for_impl();
+
+    struct CliOption<'a> {
+        field: &'a ParsedField<'a>,
+        setter: Ident,
+        /// The type of the value, which makes a `bool` option a flag.
+        ty: &'a Type,
+        /// The type the value is parsed as, which the setter accepts.
+        parsety: TokenStream,
+        /// The text of `parsety`, as shown in the help.
+        parsetext: String,
+        flag: String,
+        /// Further names of the option, from the field's deprecated aliases.
+        /// They are accepted but not shown in the help.
+        aliases: Vec<String>,
+        repeatable: bool,
+        /// Records `parse_args` as the source of the field, with
+        /// `track_source`.
+        sourceupdate: TokenStream,
+    }
+    let parseargs = Ident::new("parse_args", Span::call_site());
+    let mut options = Vec::new();
+    for (index, field) in parsed.fields.iter().enumerate() {
+        if field.cliskip.is_some() {
+            continue;
+        }
+        let sourceupdate = generate_source_update(parsed, index, &parseargs);
+        // A field with `each` is only given element by element.
+        if field.should_have_set_method() && field.vecattr.is_none() {
+            let (ty, parsety, parsetext) = match &field.setterwrap {
+                Some(ParsedSetterWrap::New(inner)) => {
+                    (inner, quote! { #inner }, get_type_text(inner))
+                }
+                Some(ParsedSetterWrap::Into(borrowed)) => {
+                    let (parsety, parsetext) = get_owned_type(borrowed);
+                    (borrowed, parsety, parsetext)
+                }
+                Some(ParsedSetterWrap::PathBuf) | None => {
+                    let ty = field.setter_ty();
+                    (ty, quote! { #ty }, get_type_text(ty))
+                }
+            };
+            options.push(CliOption {
+                field,
+                setter: field.setter_name(),
+                ty,
+                parsety,
+                parsetext,
+                flag: field.display_name().replace('_', "-"),
+                aliases: field
+                    .aliases
+                    .iter()
+                    .map(|alias| alias.unraw().to_string().replace('_', "-"))
+                    .collect(),
+                repeatable: false,
+                sourceupdate: sourceupdate.clone(),
+            });
+        }
+        if let (Some(vecattr), Some(ty)) = (&field.vecattr, get_vec_type_inner(field.ty())) {
+            options.push(CliOption {
+                field,
+                setter: vecattr.method.clone(),
+                ty,
+                parsety: quote! { #ty },
+                parsetext: get_type_text(ty),
+                flag: vecattr.method.unraw().to_string().replace('_', "-"),
+                aliases: Vec::new(),
+                repeatable: true,
+                sourceupdate,
+            });
+        }
+    }
+
+    let args = Ident::new("args", Span::mixed_site());
+    let arg = Ident::new("arg", Span::mixed_site());
+    let name = Ident::new("name", Span::mixed_site());
+    let value = Ident::new("value", Span::mixed_site());
+    let parsed = Ident::new("parsed", Span::mixed_site());
+    let err = Ident::new("err", Span::mixed_site());
+    let help = Ident::new("help", Span::mixed_site());
+    let isbool = |ty: &Type| matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("bool"));
+    let arms = options.iter().map(|option| {
+        let flag = &option.flag;
+        let aliases = &option.aliases;
+        let setter = &option.setter;
+        let parsety = &option.parsety;
+        let cfgattrs = &option.field.cfgattrs;
+        let sourceupdate = &option.sourceupdate;
+        // Blames the field's type if it cannot be parsed.
+        let parsecall = quote_spanned! {option.ty.span()=>
+            <#parsety as ::std::str::FromStr>::from_str(&#value)
+        };
+        let getvalue = if isbool(option.ty) {
+            quote! {
+                match #value {
+                    ::std::option::Option::None => true,
+                    ::std::option::Option::Some(#value) => match #value.as_str() {
+                        "true" => true,
+                        "false" => false,
+                        _ => return ::std::result::Result::Err(#argserrorname::InvalidValue {
+                            option: #flag,
+                            message: ::std::string::String::from("expected `true` or `false`"),
+                        }),
+                    },
+                }
+            }
+        } else {
+            quote! {
+                {
+                    let #value = match #value {
+                        ::std::option::Option::Some(#value) => #value,
+                        ::std::option::Option::None => match ::std::iter::Iterator::next(&mut #args) {
+                            ::std::option::Option::Some(#value) => #value,
+                            ::std::option::Option::None => {
+                                return ::std::result::Result::Err(#argserrorname::MissingValue {
+                                    option: #flag,
+                                })
+                            }
+                        },
+                    };
+                    match #parsecall {
+                        ::std::result::Result::Ok(#parsed) => #parsed,
+                        ::std::result::Result::Err(#err) => {
+                            return ::std::result::Result::Err(#argserrorname::InvalidValue {
+                                option: #flag,
+                                message: ::std::string::ToString::to_string(&#err),
+                            })
+                        }
+                    }
+                }
+            }
+        };
+        quote! {
+            #(#cfgattrs)*
+            #flag #(| #aliases)* => {
+                let #parsed = #getvalue;
+                self.#setter(#parsed);
+                #sourceupdate
+            }
+        }
+    });
+
+    let usages: Vec<String> = options
+        .iter()
+        .map(|option| {
+            if isbool(option.ty) {
+                format!("--{}", option.flag)
+            } else {
+                format!("--{} <{}>", option.flag, option.parsetext)
+            }
+        })
+        .collect();
+    let width = usages
+        .iter()
+        .map(String::len)
+        .chain(["--help".len()])
+        .max()
+        .unwrap_or_default();
+    let helplines = options.iter().zip(&usages).map(|(option, usage)| {
+        let mut description = get_doc_text(&option.field.docs)
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_owned();
+        let mut notes = Vec::new();
+        if option.repeatable {
+            notes.push("may be repeated".to_owned());
+        } else if option.field.is_required() {
+            notes.push("required".to_owned());
+        } else if let Some(default) = &option.field.defaultattr {
+            // The default of a secret field may be a credential itself.
+            if option.field.secret || default.fromtrait {
+                notes.push("default".to_owned());
+            } else {
+                notes.push(format!("default: {}", default.text));
+            }
+        }
+        if !notes.is_empty() {
+            if !description.is_empty() {
+                description.push(' ');
+            }
+            description.push_str(&format!("[{}]", notes.join(", ")));
+        }
+        let line = format!("  {:width$}  {}", usage, description, width = width)
+            .trim_end()
+            .to_owned()
+            + "\n";
+        let cfgattrs = &option.field.cfgattrs;
+        quote! {
+            #(#cfgattrs)*
+            #help.push_str(#line);
+        }
+    });
+    let helpline = format!("  {:width$}  Prints this help.\n", "--help", width = width);
+
+    let errordoc = format!(
+        "An error returned by [`{}::parse_args`].",
+        builderstructname
+    );
+    let parsedoc = format!(
+        "Sets the fields given by command-line arguments, such as `--port 80` or \
+         `--port=80`, by calling the setters of this builder.\n\n\
+         The arguments must not include the program name, so pass \
+         `std::env::args().skip(1)`. See [`{}::help`] for the accepted options.\n\n\
+         # Errors\n\n\
+         Returns an error for an unknown option, an option without a value, or \
+         a value its type cannot parse. `--help` returns \
+         [`{}::Help`], for the caller to print the help and exit.",
+        builderstructname, argserrorname
+    );
+    let formatter = Ident::new("f", Span::mixed_site());
+    Ok(quote! {
+        #[doc = #errordoc]
+        #[derive(::std::fmt::Debug, ::std::clone::Clone, ::std::cmp::PartialEq, ::std::cmp::Eq)]
+        pub enum #argserrorname {
+            /// `--help` was given.
+            Help,
+            /// An argument is not one of the options, or not an option at all.
+            Unknown {
+                /// The argument as given.
+                argument: ::std::string::String,
+            },
+            /// An option that takes a value was given last, without one.
+            MissingValue {
+                /// The option, without the leading dashes.
+                option: &'static str,
+            },
+            /// The value of an option could not be parsed.
+            InvalidValue {
+                /// The option, without the leading dashes.
+                option: &'static str,
+                /// Why the value could not be parsed.
+                message: ::std::string::String,
+            },
+        }
+
+        impl ::std::fmt::Display for #argserrorname {
+            fn fmt(&self, #formatter: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    Self::Help => ::std::fmt::Formatter::write_str(#formatter, "help requested"),
+                    Self::Unknown { argument } => {
+                        ::std::write!(#formatter, "unknown argument `{}`", argument)
+                    }
+                    Self::MissingValue { option } => {
+                        ::std::write!(#formatter, "`--{}` needs a value", option)
+                    }
+                    Self::InvalidValue { option, message } => {
+                        ::std::write!(#formatter, "invalid value for `--{}`: {}", option, message)
+                    }
+                }
+            }
+        }
+
+        impl ::std::error::Error for #argserrorname {}
+
+        impl #impl_generics #builderstructname #ty_generics #where_clause {
+            #[doc = #parsedoc]
+            pub fn parse_args(
+                &mut self,
+                #args: impl ::std::iter::IntoIterator<Item = impl ::std::convert::Into<::std::string::String>>,
+            ) -> ::std::result::Result<&mut Self, #argserrorname> {
+                let mut #args = ::std::iter::Iterator::map(
+                    ::std::iter::IntoIterator::into_iter(#args),
+                    ::std::convert::Into::<::std::string::String>::into,
+                );
+                while let ::std::option::Option::Some(#arg) = ::std::iter::Iterator::next(&mut #args) {
+                    let (#name, #value) = match #arg.strip_prefix("--") {
+                        ::std::option::Option::Some(#name) => match #name.split_once('=') {
+                            ::std::option::Option::Some((#name, #value)) => (
+                                ::std::borrow::ToOwned::to_owned(#name),
+                                ::std::option::Option::Some(::std::borrow::ToOwned::to_owned(#value)),
+                            ),
+                            ::std::option::Option::None => {
+                                (::std::borrow::ToOwned::to_owned(#name), ::std::option::Option::None)
+                            }
+                        },
+                        ::std::option::Option::None => {
+                            return ::std::result::Result::Err(#argserrorname::Unknown { argument: #arg })
+                        }
+                    };
+                    match #name.as_str() {
+                        "help" => return ::std::result::Result::Err(#argserrorname::Help),
+                        #(#arms)*
+                        _ => {
+                            return ::std::result::Result::Err(#argserrorname::Unknown { argument: #arg })
+                        }
+                    }
+                }
+                ::std::result::Result::Ok(self)
+            }
+
+            /// Describes the options accepted by `parse_args`.
+            pub fn help() -> ::std::string::String {
+                let mut #help = ::std::string::String::from("Options:\n");
+                #(#helplines)*
+                #help.push_str(#helpline);
+                #help
+            }
+        }
+    })
+}
+
+/// Generates the `<Struct>Patch` type holding an optional value for every
+/// field, `<Struct>::apply` to update a value with a patch, and
+/// `<Builder>::build_partial` to turn whatever has been set on a builder into a
+/// patch.
+fn generate_patch(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let fields = &parsed.fields;
+    let vis = &inputtree.vis;
+    let structname = &inputtree.ident;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let patchstructname = format_ident!("{}Patch", structname);
+    let (impl_generics, ty_generics, where_clause) = inputtree.generics.split_for_impl();
+    if let Some(build) = fields.iter().find_map(|field| field.build_expr()) {
+        return Err(syn::Error::new(
+            build.span(),
+            "`patch` does not support `field(build = \"...\")`, \
+             since the build expression may depend on other fields",
+        ));
+    }
+    if let Some(ty) = fields
+        .iter()
+        .filter_map(|field| field.storageattr.as_ref())
+        .find_map(|storage| storage.ty.as_ref())
+    {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "`patch` does not support `field(ty = \"...\")`, since a custom \
+             storage type cannot tell whether the field was set",
+        ));
+    }
+
+    let patchfields = fields.iter().map(|field| {
+        let name = field.name();
+        let ty = field.ty();
+        let cfgattrs = &field.cfgattrs;
+        let docs = &field.docs;
+        quote! {
+            #(#docs)*
+            #(#cfgattrs)*
+            #vis #name: ::std::option::Option<#ty>
+        }
+    });
+    let applyfields = fields.iter().map(|field| {
+        let name = field.name();
+        let local = field.local_name();
+        let cfgattrs = &field.cfgattrs;
+        quote! {
+            #(#cfgattrs)*
+            if let ::std::option::Option::Some(#local) = patch.#name {
+                self.#name = #local;
+            }
+        }
+    });
+    // A derived `Default` would require every type parameter to implement it,
+    // while the fields are all `Option`s.
+    let nonefields = fields.iter().map(|field| {
+        let name = field.name();
+        let cfgattrs = &field.cfgattrs;
+        quote! { #(#cfgattrs)* #name: ::std::option::Option::None }
+    });
+    let inner = Ident::new("inner", Span::mixed_site());
+    let partialfields = fields.iter().map(|field| {
+        let name = field.name();
+        let cfgattrs = &field.cfgattrs;
+        let value = if field.requiredarg {
+            quote! { ::std::option::Option::Some(::std::clone::Clone::clone(&self.#name)) }
+        } else if field.has_vec_attribute() {
+            quote! {
+                match &self.#name {
+                    ::std::option::Option::Some(#inner) if !#inner.is_empty() => {
+                        ::std::option::Option::Some(::std::clone::Clone::clone(#inner))
+                    }
+                    _ => ::std::option::Option::None,
+                }
+            }
+        } else if is_option_type(field.ty()) {
+            quote! {
+                match &self.#name {
+                    ::std::option::Option::Some(#inner) => ::std::option::Option::Some(
+                        ::std::option::Option::Some(::std::clone::Clone::clone(#inner))
+                    ),
+                    ::std::option::Option::None => ::std::option::Option::None,
+                }
+            }
+        } else {
+            quote! { ::std::clone::Clone::clone(&self.#name) }
+        };
+        quote! { #(#cfgattrs)* #name: #value }
+    });
+
+    let patchdoc = format!(
+        "A partial update of a [`{0}`], holding a new value for each field that \
+         is `Some`.\n\nApply it with [`{0}::apply`], and create one with \
+         [`{1}::build_partial`] or by setting its fields directly.",
+        structname, builderstructname
+    );
+    let applydoc = format!(
+        "Overwrites each field of this `{}` whose value in `patch` is `Some`.",
+        structname
+    );
+    let partialdoc = format!(
+        "Collects the fields set on this builder into a [`{}`], without checking \
+         that required fields are set or applying defaults.\n\nFields filled \
+         with `each` count as set once they hold at least one element.",
+        patchstructname
+    );
+    Ok(quote! {
+        #[doc = #patchdoc]
+        #vis struct #patchstructname #impl_generics #where_clause {
+            #(#patchfields),*
+        }
+
+        impl #impl_generics ::std::default::Default for #patchstructname #ty_generics #where_clause {
+            fn default() -> Self {
+                #patchstructname {
+                    #(#nonefields),*
+                }
+            }
+        }
+
+        impl #impl_generics #structname #ty_generics #where_clause {
+            #[doc = #applydoc]
+            #vis fn apply(&mut self, patch: #patchstructname #ty_generics) {
+                #(#applyfields)*
+            }
+        }
+
+        impl #impl_generics #builderstructname #ty_generics #where_clause {
+            #[doc = #partialdoc]
+            pub fn build_partial(&self) -> #patchstructname #ty_generics {
+                #patchstructname {
+                    #(#partialfields),*
+                }
+            }
+        }
+    })
+}
+
+fn generate_builder_impl_new_method(parsed: &ParsedStruct) -> Result<TokenStream, syn::Error> {
+    let inputtree = parsed.inputtree;
+    let builderstructname = get_builder_struct_name(inputtree);
+    let constness = get_constness(parsed);
+    let (params, _) = generate_required_args(&parsed.fields);
+    let doc = format!(
+        "Creates a builder with {}.",
+        describe_required_args(&parsed.fields)
+    );
+    let fields = parsed.fields.iter().map(|it| {
+        let name = it.name();
+        let cfgattrs = &it.cfgattrs;
+        if it.requiredarg {
+            let setter = it.setter_name();
+            quote! { #(#cfgattrs)* #name: #setter }
+        } else if it.has_custom_storage_type() {
+            quote! { #(#cfgattrs)* #name: ::std::default::Default::default() }
+        } else if it.has_vec_attribute() {
+            quote! { #(#cfgattrs)* #name: ::std::option::Option::Some(::std::vec::Vec::new()) }
+        } else {
+            quote! { #(#cfgattrs)* #name: ::std::option::Option::None }
+        }
+    });
+    let sourcefields = if parsed.attributes.tracksource {
+        let sourcename = get_builder_source_name(inputtree);
+        let (sources, label) = get_source_field_names();
+        let initial = parsed.fields.iter().map(|field| {
+            if field.requiredarg {
+                quote! { ::std::option::Option::Some(#sourcename::Argument) }
+            } else {
+                quote! { ::std::option::Option::None }
+            }
+        });
+        quote! {
+            #sources: [#(#initial),*],
+            #label: ::std::option::Option::None,
+        }
+    } else {
+        TokenStream::new()
+    };
+    let output = quote! {
+        #[doc = #doc]
+        pub #constness fn new(#(#params),*) -> Self {
+            #builderstructname {
+                #(#fields,)*
+                #sourcefields
+            }
+        }
+    };
+    Ok(output)
+}
+
+/// Generates the builder of the function or the methods of the impl block
+/// that `#[builder(args)]` is applied to.
+pub fn generate_fn_builder_code(args: TokenStream, item: Item) -> Result<TokenStream, syn::Error> {
+    match item {
+        Item::Fn(mut itemfn) => {
+            let vis = itemfn.vis.clone();
+            let fnbuilder = generate_fn_builder(args, &vis, &mut itemfn.sig, None)?;
+            let factory = fnbuilder.factory;
+            let items = fnbuilder.items;
+            Ok(quote! {
+                #itemfn
+                #factory
+                #items
+            })
+        }
+        Item::Impl(itemimpl) => {
+            if !args.is_empty() {
+                return Err(syn::Error::new(
+                    args.span(),
+                    "options go on the methods of the impl block, e.g. `#[builder(derive(Debug))]`",
+                ));
+            }
+            generate_impl_builder_code(itemimpl)
+        }
+        other => Err(syn::Error::new(
+            other.span(),
+            "`#[builder]` can only be used on functions and on impl blocks",
+        )),
+    }
+}
+
+fn generate_impl_builder_code(mut itemimpl: ItemImpl) -> Result<TokenStream, syn::Error> {
+    if let Some((_, path, _)) = &itemimpl.trait_ {
+        return Err(syn::Error::new(
+            path.span(),
+            "`#[builder]` cannot add methods to a trait impl, use an inherent impl block instead",
+        ));
+    }
+    if !itemimpl.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            itemimpl.generics.span(),
+            "`#[builder]` does not support generic impl blocks",
+        ));
+    }
+    let selfty = itemimpl.self_ty.clone();
+    let mut factories = Vec::new();
+    let mut items = Vec::new();
+    for implitem in itemimpl.items.iter_mut() {
+        if let ImplItem::Method(method) = implitem {
+            let position = match method.attrs.iter().position(is_builder_attribute) {
+                Some(position) => position,
+                None => continue,
+            };
+            let attr = method.attrs.remove(position);
+            let args = parse_fn_builder_args(&attr)?;
+            let fnbuilder = generate_fn_builder(args, &method.vis, &mut method.sig, Some(&selfty))?;
+            factories.push(fnbuilder.factory);
+            items.push(fnbuilder.items);
+        }
+    }
+    itemimpl
+        .items
+        .extend(factories.into_iter().map(ImplItem::Verbatim));
+    Ok(quote! {
+        #itemimpl
+        #(#items)*
+    })
+}
+
+fn parse_fn_builder_args(attr: &Attribute) -> Result<TokenStream, syn::Error> {
+    if attr.tokens.is_empty() {
+        return Ok(TokenStream::new());
+    }
+    if let Meta::List(metalist) = attr.parse_meta()? {
+        let nested = metalist.nested;
+        Ok(quote! { #nested })
+    } else {
+        Err(syn::Error::new(
+            attr.tokens.span(),
+            "expected `builder` or `builder(...)`",
+        ))
+    }
+}
+
+struct FnBuilder {
+    /// The function returning a new builder, placed next to the function.
+    factory: TokenStream,
+    /// The arguments struct, its builder and the builder's `call` method.
+    items: TokenStream,
+}
+
+/// Generates a builder for the arguments of a function, or of a method when
+/// `selfty` is given.
+///
+/// The arguments are gathered in a struct with one field per argument, and
+/// the struct's builder is generated exactly as for `#[derive(Builder)]`, so
+/// every field option is available on the arguments. The builder gets a `call`
+/// method which builds the arguments and calls the function with them.
+fn generate_fn_builder(
+    args: TokenStream,
+    vis: &Visibility,
+    sig: &mut Signature,
+    selfty: Option<&Type>,
+) -> Result<FnBuilder, syn::Error> {
+    if let Some(param) = sig
+        .generics
+        .params
+        .iter()
+        .find(|param| !matches!(param, GenericParam::Lifetime(_)))
+    {
+        return Err(syn::Error::new(
+            param.span(),
+            "`#[builder]` does not support functions with type or const parameters",
+        ));
+    }
+    if let ReturnType::Type(_, ty) = &sig.output {
+        let mut ty = (**ty).clone();
+        let mut elided = ElidedLifetimes::default();
+        elided.visit_type_mut(&mut ty);
+        if let Some(span) = elided.spans.first() {
+            return Err(syn::Error::new(
+                *span,
+                "`#[builder]` needs the lifetimes in the return type to be named",
+            ));
+        }
+    }
+
+    let fnname = &sig.ident;
+    let fnnamestring = fnname.unraw().to_string();
+    let argsstructname = match selfty {
+        Some(selfty) => format_ident!(
+            "{}{}Args",
+            get_type_name(selfty)?,
+            to_pascal_case(&fnnamestring)
+        ),
+        None => format_ident!("{}Args", to_pascal_case(&fnnamestring)),
+    };
+    let factoryname = format_ident!("{}_builder", fnnamestring);
+    let callpath = match selfty {
+        Some(selfty) => quote! { <#selfty>::#fnname },
+        None => quote! { #fnname },
+    };
+    let calltarget = match selfty {
+        Some(selfty) => format!("{}::{}", get_type_name(selfty)?, fnnamestring),
+        None => fnnamestring.clone(),
+    };
+
+    let mut elided = ElidedLifetimes::default();
+    let mut receiver = None;
+    let mut fields = Vec::new();
+    let mut argnames = Vec::new();
+    for input in sig.inputs.iter_mut() {
+        match input {
+            FnArg::Receiver(rec) => {
+                let ty = match &rec.reference {
+                    Some((_, lifetime)) => {
+                        let mutability = &rec.mutability;
+                        quote! { &#lifetime #mutability #selfty }
+                    }
+                    None => quote! { #selfty },
+                };
+                receiver = Some(ty);
+            }
+            FnArg::Typed(pattype) => {
+                let ident = match &*pattype.pat {
+                    Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => {
+                        pat.ident.clone()
+                    }
+                    pat => {
+                        return Err(syn::Error::new(
+                            pat.span(),
+                            "`#[builder]` needs every argument to be a plain name",
+                        ))
+                    }
+                };
+                if ident == "self" {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "`#[builder]` only supports `self`, `&self` and `&mut self` receivers",
+                    ));
+                }
+                if let Some(attr) = pattype
+                    .attrs
+                    .iter()
+                    .find(|attr| attr.path.is_ident("cfg") || attr.path.is_ident("cfg_attr"))
+                {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        "`#[builder]` does not support conditionally compiled arguments",
+                    ));
+                }
+                let builderattrs: Vec<Attribute> = pattype
+                    .attrs
+                    .iter()
+                    .filter(|attr| is_builder_attribute(attr))
+                    .cloned()
+                    .collect();
+                pattype.attrs.retain(|attr| !is_builder_attribute(attr));
+                let mut ty = (*pattype.ty).clone();
+                elided.visit_type_mut(&mut ty);
+                fields.push(quote! { #(#builderattrs)* #ident: #ty });
+                argnames.push(ident);
+            }
+        }
+    }
+
+    let lifetimes =
+        sig.generics
+            .params
+            .iter()
+            .cloned()
+            .chain(
+                elided.lifetimes.iter().map(|lifetime| {
+                    GenericParam::Lifetime(syn::LifetimeDef::new(lifetime.clone()))
+                }),
+            )
+            .collect::<Vec<_>>();
+    let whereclause = &sig.generics.where_clause;
+    let structargs = if args.is_empty() {
+        quote! {}
+    } else {
+        quote! { #[builder(#args)] }
+    };
+    let structdoc = format!("The arguments of [`{}`].", calltarget);
+    let inputtree: DeriveInput = syn::parse2(quote! {
+        #[doc = #structdoc]
+        #structargs
+        #vis struct #argsstructname <#(#lifetimes),*> #whereclause {
+            #(#fields),*
+        }
+    })?;
+    let parsed = ParsedStruct::new(&inputtree)?;
+    if parsed.attributes.constbuilder {
+        return Err(syn::Error::new(
+            args.span(),
+            "`#[builder(const)]` is not supported on functions",
+        ));
+    }
+    // The emitted struct must not carry `#[builder]` attributes, which would
+    // invoke this attribute macro again rather than being inert.
+    let mut argsstruct = inputtree.clone();
+    argsstruct.attrs.retain(|attr| !is_builder_attribute(attr));
+    if let Data::Struct(datastruct) = &mut argsstruct.data {
+        for field in datastruct.fields.iter_mut() {
+            field.attrs.retain(|attr| !is_builder_attribute(attr));
+        }
+    }
+    let derivedcode = generate_builder_code(&parsed, &["call"])?;
+
+    let builderstructname = get_builder_struct_name(&inputtree);
+    let buildererrorname = get_builder_error_name(&inputtree);
+    let (impl_generics, ty_generics, where_clause) = inputtree.generics.split_for_impl();
+    let locals: Vec<Ident> = argnames
+        .iter()
+        .map(|name| {
+            let mut local = name.clone();
+            local.set_span(Span::mixed_site());
+            local
+        })
+        .collect();
+    let (receiverparam, receiverarg) = match &receiver {
+        Some(ty) => {
+            let name = Ident::new("receiver", Span::mixed_site());
+            (quote! { , #name: #ty }, quote! { #name, })
+        }
+        None => (quote! {}, quote! {}),
+    };
+    let asyncbuild = parsed.attributes.asyncbuild;
+    let asyncness = if sig.asyncness.is_some() || asyncbuild {
+        quote! { async }
+    } else {
+        quote! {}
+    };
+    let awaitcall = if sig.asyncness.is_some() {
+        quote! { .await }
+    } else {
+        quote! {}
+    };
+    let awaitbuild = if asyncbuild {
+        quote! { .await }
+    } else {
+        quote! {}
+    };
+    let output = match &sig.output {
+        ReturnType::Default => quote! { () },
+        ReturnType::Type(_, ty) => quote! { #ty },
+    };
+    let calldoc = format!(
+        "Calls [`{}`] with the arguments set on this builder.\n\n# Errors\n\n\
+         Returns an error if building the arguments fails, see [`{}::build`].",
+        calltarget, builderstructname
+    );
+    let factorydoc = format!(
+        "Creates a [`{}`] to set the arguments of [`{}`] one by one.",
+        builderstructname, calltarget
+    );
+
+    let (params, args) = generate_required_args(&parsed.fields);
+    let factory = quote! {
+        #[doc = #factorydoc]
+        #vis fn #factoryname #impl_generics (#(#params),*) -> #builderstructname #ty_generics #where_clause {
+            #argsstructname::builder(#(#args),*)
+        }
+    };
+    let items = quote! {
+        #argsstruct
+
+        #derivedcode
+
+        impl #impl_generics #builderstructname #ty_generics #where_clause {
+            #[doc = #calldoc]
+            #vis #asyncness fn call(&mut self #receiverparam)
+                -> ::std::result::Result<#output, #buildererrorname>
+            {
+                let #argsstructname { #(#argnames: #locals),* } = self.build() #awaitbuild ?;
+                ::std::result::Result::Ok(#callpath(#receiverarg #(#locals),*) #awaitcall)
+            }
+        }
+    };
+    Ok(FnBuilder { factory, items })
+}
+
+/// Replaces elided lifetimes, as in `&str` or `Cow<'_, str>`, with named
+/// lifetimes so that the types can be used as fields of a struct.
+#[derive(Default)]
+struct ElidedLifetimes {
+    lifetimes: Vec<Lifetime>,
+    spans: Vec<Span>,
+}
+
+impl ElidedLifetimes {
+    fn next_lifetime(&mut self, span: Span) -> Lifetime {
+        let lifetime = Lifetime::new(&format!("'__builder{}", self.lifetimes.len()), span);
+        self.lifetimes.push(lifetime.clone());
+        self.spans.push(span);
+        lifetime
+    }
+}
+
+impl VisitMut for ElidedLifetimes {
+    fn visit_type_reference_mut(&mut self, reference: &mut TypeReference) {
+        if reference.lifetime.is_none() {
+            reference.lifetime = Some(self.next_lifetime(reference.and_token.span));
+        }
+        syn::visit_mut::visit_type_reference_mut(self, reference);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "_" {
+            *lifetime = self.next_lifetime(lifetime.span());
+        }
+    }
+}
+
+fn get_type_name(ty: &Type) -> Result<&Ident, syn::Error> {
+    match ty {
+        Type::Path(typepath) if typepath.qself.is_none() => {
+            Ok(&typepath.path.segments.last().unwrap().ident)
+        }
+        _ => Err(syn::Error::new(
+            ty.span(),
+            "`#[builder]` only supports impl blocks for named types",
+        )),
+    }
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn get_parsed_field(inputtree: &DeriveInput) -> Result<Vec<ParsedField<'_>>, syn::Error> {
+    let datastruct =
+        match &inputtree.data {
+            Data::Struct(datastruct) => datastruct,
+            Data::Enum(dataenum) => {
+                return Err(syn::Error::new(
+                    dataenum.enum_token.span(),
+                    "`Builder` cannot be derived for an enum; derive it on a struct with named \
+                 fields instead, e.g. one struct per variant",
+                ))
+            }
+            Data::Union(dataunion) => return Err(syn::Error::new(
+                dataunion.union_token.span(),
+                "`Builder` cannot be derived for a union; use a struct with named fields instead",
+            )),
+        };
+    let fields = match &datastruct.fields {
+        Fields::Named(namedfields) => namedfields.named.iter().map(ParsedField::new),
+        Fields::Unnamed(unnamedfields) => {
+            return Err(syn::Error::new(
+                unnamedfields.span(),
+                "`Builder` needs named fields, since its setters are named after them; \
+                 name the fields, e.g. `struct Meters { value: f64 }`",
+            ))
+        }
+        Fields::Unit => {
+            return Err(syn::Error::new(
+                inputtree.ident.span(),
+                "`Builder` cannot be derived for a unit struct, which has no fields to set; \
+                 add named fields, or construct the struct directly",
+            ))
+        }
+    };
+
+    fields.collect()
+}
+
+fn get_constness(parsed: &ParsedStruct) -> TokenStream {
+    if parsed.attributes.constbuilder {
+        quote! { const }
+    } else {
+        quote! {}
+    }
+}
+
+fn get_builder_struct_name(inputtree: &DeriveInput) -> Ident {
+    format_ident!("{}{}", inputtree.ident, "Builder")
+}
+
+fn get_builder_error_name(inputtree: &DeriveInput) -> Ident {
+    format_ident!("{}BuilderError", inputtree.ident)
+}
+
+fn get_builder_field_info_name(inputtree: &DeriveInput) -> Ident {
+    format_ident!("{}BuilderFieldInfo", inputtree.ident)
+}
+
+/// A type as it would be written by hand, for use in messages and metadata.
+fn get_type_text(ty: &Type) -> String {
+    quote!(#ty)
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
+
+/// The owned type of a borrowed type, with its text: `String` for `str`, and
+/// so on. Other types are left to their `ToOwned` implementation.
+fn get_owned_type(borrowed: &Type) -> (TokenStream, String) {
+    let name = match borrowed {
+        Type::Path(path) if path.qself.is_none() => {
+            path.path.segments.last().map(|it| it.ident.to_string())
+        }
+        Type::Slice(slice) => {
+            let elem = &slice.elem;
+            return (
+                quote! { ::std::vec::Vec<#elem> },
+                format!("Vec<{}>", get_type_text(elem)),
+            );
+        }
+        _ => None,
+    };
+    let (owned, text) = match name.as_deref() {
+        Some("str") => (quote! { ::std::string::String }, "String"),
+        Some("Path") => (quote! { ::std::path::PathBuf }, "PathBuf"),
+        Some("OsStr") => (quote! { ::std::ffi::OsString }, "OsString"),
+        Some("CStr") => (quote! { ::std::ffi::CString }, "CString"),
+        _ => {
+            return (
+                quote! { <#borrowed as ::std::borrow::ToOwned>::Owned },
+                get_type_text(borrowed),
+            )
+        }
+    };
+    (owned, text.to_owned())
+}
+
+/// The text of `#[doc = "..."]` attributes, one line per attribute, without
+/// the space that follows `///`.
+fn get_doc_text(docs: &[Attribute]) -> String {
+    docs.iter()
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(value)) => match value.lit {
+                Lit::Str(lit) => Some(lit.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn get_builder_source_name(inputtree: &DeriveInput) -> Ident {
+    format_ident!("{}BuilderSource", inputtree.ident)
+}
+
+/// The private fields of a builder with `track_source`: the source of each
+/// field, indexed like the struct's fields, and the current label.
+fn get_source_field_names() -> (Ident, Ident) {
+    (
+        format_ident!("__builder_sources"),
+        format_ident!("__builder_source_label"),
+    )
+}
+
+/// The statement a method of a builder with `track_source` runs to record
+/// that it set the field at `index`.
+fn generate_source_update(parsed: &ParsedStruct, index: usize, method: &Ident) -> TokenStream {
+    if !parsed.attributes.tracksource {
+        return TokenStream::new();
+    }
+    let position = Literal::usize_unsuffixed(index);
+    let sourcename = get_builder_source_name(parsed.inputtree);
+    let (sources, label) = get_source_field_names();
+    let method = method.unraw().to_string();
+    quote! {
+        self.#sources[#position] = ::std::option::Option::Some(#sourcename::Setter {
+            method: #method,
+            label: ::std::clone::Clone::clone(&self.#label),
+        });
+    }
+}
+
+fn get_container_type_inner<'a>(ty: &'a Type, containername: &str) -> Option<&'a Type> {
+    if let Type::Path(typepath) = ty {
+        if let Some(seg) = typepath.path.segments.iter().last() {
+            if seg.ident == containername {
+                if let PathArguments::AngleBracketed(args) = &seg.arguments {
+                    // Skips lifetimes, as in `Cow<'a, str>`.
+                    return args.args.iter().find_map(|arg| match arg {
+                        GenericArgument::Type(inner) => Some(inner),
+                        _ => None,
+                    });
+                }
+            }
+        }
+    };
+    None
+}
+
+fn get_option_type_inner(ty: &Type) -> Option<&Type> {
+    get_container_type_inner(ty, "Option")
+}
+
+fn get_vec_type_inner(ty: &Type) -> Option<&Type> {
+    get_container_type_inner(ty, "Vec")
+}
+
+fn is_option_type(ty: &Type) -> bool {
+    get_option_type_inner(ty).is_some()
+}
+
+#[derive(Clone)]
+struct ParsedField<'a> {
+    field: &'a Field,
+    vecattr: Option<ParsedVecAttribute>,
+    storageattr: Option<ParsedStorageAttribute>,
+    defaultattr: Option<ParsedDefaultAttribute>,
+    constraints: Vec<ParsedConstraint>,
+    requiredarg: bool,
+    secret: bool,
+    /// Where `cli(skip)` was given, which leaves the field out of
+    /// `parse_args`.
+    cliskip: Option<Span>,
+    setterwrap: Option<ParsedSetterWrap>,
+    aliases: Vec<Ident>,
+    deprecated: Option<LitStr>,
+    fieldattrs: Vec<Meta>,
+    setterattrs: Vec<Meta>,
+    docs: Vec<Attribute>,
+    cfgattrs: Vec<TokenStream>,
+    _name: Ident,
+}
+
+impl<'a> ParsedField<'a> {
+    fn new(field: &'a Field) -> Result<Self, syn::Error> {
+        let mut vecattr = None;
+        let mut storageattr = None;
+        let mut defaultattr = None;
+        let mut constraints = Vec::new();
+        let mut requiredarg = None;
+        let mut secret = false;
+        let mut cliskip = None;
+        let mut setterwrap = None;
+        let mut aliases = Vec::new();
+        let mut deprecated = None;
+        let mut fieldattrs = Vec::new();
+        let mut setterattrs = Vec::new();
+        for attr in field.attrs.iter().filter(|attr| is_builder_attribute(attr)) {
+            let err = || syn::Error::new(attr.tokens.span(), "expected `builder(each = \"...\")`");
+            for item in parse_builder_attribute_items(attr, err)? {
+                match &item {
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("each") => {
+                        if let Lit::Str(lit) = &value.lit {
+                            vecattr = Some(ParsedVecAttribute::new(lit)?);
+                        } else {
+                            return Err(err());
+                        }
+                    }
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("default") => {
+                        defaultattr = Some(ParsedDefaultAttribute::new(&value.lit)?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(value))
+                        if value.path.is_ident("default_async") =>
+                    {
+                        defaultattr = Some(ParsedDefaultAttribute::new_async(&value.lit)?);
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("default") => {
+                        defaultattr = Some(ParsedDefaultAttribute::default_trait(path));
+                    }
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("validate") => {
+                        constraints.push(ParsedConstraint::validate(&value.lit)?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("regex") => {
+                        constraints.push(ParsedConstraint::regex(&value.lit)?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("alias") => {
+                        if let Lit::Str(lit) = &value.lit {
+                            let name = lit.value();
+                            let name = name.strip_prefix("r#").unwrap_or(&name);
+                            aliases.push(ident_from_name(name, lit.span())?);
+                        } else {
+                            return Err(syn::Error::new(
+                                value.lit.span(),
+                                "expected the alias as a string, e.g. `alias = \"old_name\"`",
+                            ));
+                        }
+                    }
+                    NestedMeta::Meta(Meta::NameValue(value))
+                        if value.path.is_ident("deprecated") =>
+                    {
+                        if let Lit::Str(lit) = &value.lit {
+                            deprecated = Some(lit.clone());
+                        } else {
+                            return Err(syn::Error::new(
+                                value.lit.span(),
+                                "expected the deprecation note as a string, \
+                                 e.g. `deprecated = \"use `new_name`\"`",
+                            ));
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("required_arg") => {
+                        requiredarg = Some(path.span());
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("secret") => {
+                        secret = true;
+                    }
+                    NestedMeta::Meta(Meta::List(metalist)) if metalist.path.is_ident("cli") => {
+                        match metalist.nested.iter().collect::<Vec<_>>().as_slice() {
+                            [NestedMeta::Meta(Meta::Path(path))] if path.is_ident("skip") => {
+                                cliskip = Some(metalist.span());
+                            }
+                            _ => {
+                                return Err(syn::Error::new(
+                                    metalist.nested.span(),
+                                    "expected `cli(skip)`",
+                                ))
+                            }
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("non_empty") => {
+                        constraints.push(ParsedConstraint::NonEmpty(path.clone()));
+                    }
+                    NestedMeta::Meta(Meta::List(metalist)) if metalist.path.is_ident("range") => {
+                        constraints.push(ParsedConstraint::range(metalist)?);
+                    }
+                    NestedMeta::Meta(Meta::List(metalist)) if metalist.path.is_ident("field") => {
+                        storageattr = Some(ParsedStorageAttribute::new(metalist)?);
+                    }
+                    NestedMeta::Meta(Meta::List(metalist))
+                        if metalist.path.is_ident("field_attr") =>
+                    {
+                        fieldattrs.extend(parse_forwarded_attributes(metalist)?);
+                    }
+                    NestedMeta::Meta(Meta::List(metalist)) if metalist.path.is_ident("setter") => {
+                        setterwrap = Some(parse_setter_options(metalist)?);
+                    }
+                    NestedMeta::Meta(Meta::List(metalist))
+                        if metalist.path.is_ident("setter_attr") =>
+                    {
+                        setterattrs.extend(parse_forwarded_attributes(metalist)?);
+                    }
+                    _ => return Err(err()),
+                }
+            }
+        }
+        let docs = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"))
+            .cloned()
+            .collect();
+        let cfgattrs = field
+            .attrs
+            .iter()
+            .map(parse_cfg_attribute)
+            .collect::<Result<Vec<Option<TokenStream>>, syn::Error>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        if let (Some(vec), Some(ParsedStorageAttribute { ty: Some(ty), .. })) =
+            (&vecattr, &storageattr)
+        {
+            let mut err = syn::Error::new(
+                vec.method.span(),
+                "`each` cannot be combined with a custom storage type",
+            );
+            err.combine(syn::Error::new(ty.span(), "custom storage type given here"));
+            return Err(err);
+        }
+        if let Some(vec) = &vecattr {
+            if get_vec_type_inner(&field.ty).is_none() {
+                let mut err =
+                    syn::Error::new(
+                        vec.method.span(),
+                        format!(
+                        "`each` needs a field of type `Vec<T>`; remove it to set `{}` as a whole",
+                        field.ident.as_ref().map_or_else(String::new, |it| it.unraw().to_string())
+                    ),
+                    );
+                err.combine(syn::Error::new(field.ty.span(), "field type given here"));
+                return Err(err);
+            }
+        }
+        if let Some(span) = requiredarg {
+            if vecattr.is_some() || storageattr.is_some() || defaultattr.is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "`required_arg` cannot be combined with `each`, `default` or `field(...)`, \
+                     since the field is always given to `builder()`",
+                ));
+            }
+        }
+        if let Some(default) = &defaultattr {
+            if vecattr.is_some() || storageattr.is_some() {
+                return Err(syn::Error::new(
+                    default.expr.span(),
+                    "`default` cannot be combined with `each` or `field(...)`, \
+                     which already decide the value of an unset field",
+                ));
+            }
+        }
+        if let Some(note) = &deprecated {
+            if aliases.is_empty() {
+                return Err(syn::Error::new(
+                    note.span(),
+                    "`deprecated` gives the note of the field's deprecated aliases; \
+                     add one with `alias = \"...\"`",
+                ));
+            }
+        }
+        if let Some(alias) = aliases.first() {
+            if requiredarg.is_some()
+                || vecattr
+                    .as_ref()
+                    .zip(field.ident.as_ref())
+                    .is_some_and(|(vecattr, name)| vecattr.method.unraw() == name.unraw())
+            {
+                return Err(syn::Error::new(
+                    alias.span(),
+                    "`alias` needs the field to have a setter, which fields with `required_arg`, \
+                     or with `each` named like the field, do not have",
+                ));
+            }
+        }
+        let setterwrap = match setterwrap {
+            Some(path) if requiredarg.is_some() || vecattr.is_some() => {
+                return Err(syn::Error::new(
+                    path.span(),
+                    "`setter(wrap)` cannot be combined with `required_arg` or `each`, \
+                     since it only changes the setter of the whole field",
+                ))
+            }
+            Some(path) => {
+                let ty = match &storageattr {
+                    Some(ParsedStorageAttribute { ty: Some(ty), .. }) => ty,
+                    _ => get_option_type_inner(&field.ty).unwrap_or(&field.ty),
+                };
+                Some(ParsedSetterWrap::new(ty, &path)?)
+            }
+            None => None,
+        };
+        Ok(Self {
+            field,
+            vecattr,
+            storageattr,
+            defaultattr,
+            constraints,
+            requiredarg: requiredarg.is_some(),
+            secret,
+            cliskip,
+            setterwrap,
+            aliases,
+            deprecated,
+            fieldattrs,
+            setterattrs,
+            docs,
+            cfgattrs,
+            _name: field
+                .ident
+                .clone()
+                .expect("only named fields are supported"),
+        })
+    }
+
+    fn name(&self) -> &Ident {
+        &self._name
+    }
+
+    /// The name of the local variable holding this field's value in the
+    /// generated build method. It has mixed-site hygiene, so it can neither
+    /// clash with nor be seen by user-written code such as build expressions.
+    fn local_name(&self) -> Ident {
+        let mut local = self._name.clone();
+        local.set_span(Span::mixed_site());
+        local
+    }
+
+    fn ty(&self) -> &Type {
+        &self.field.ty
+    }
+
+    /// The type accepted by the setter, which is also the type of the value
+    /// held by the builder.
+    fn setter_ty(&self) -> &Type {
+        match &self.storageattr {
+            Some(ParsedStorageAttribute { ty: Some(ty), .. }) => ty,
+            _ => get_option_type_inner(self.ty()).unwrap_or_else(|| self.ty()),
+        }
+    }
+
+    /// Whether the builder stores this field as `#[builder(field(ty = "..."))]`
+    /// rather than as an `Option` of the field's own type.
+    fn has_custom_storage_type(&self) -> bool {
+        matches!(
+            &self.storageattr,
+            Some(ParsedStorageAttribute { ty: Some(_), .. })
+        )
+    }
+
+    fn build_expr(&self) -> Option<&Expr> {
+        self.storageattr.as_ref().and_then(|it| it.build.as_ref())
+    }
+
+    /// The field's name without any `r#` prefix, for use in messages and
+    /// documentation.
+    fn display_name(&self) -> String {
+        self._name.unraw().to_string()
+    }
+
+    /// The name of the setter, which is only a raw identifier when the field
+    /// name is a keyword.
+    fn setter_name(&self) -> Ident {
+        ident_from_name(&self.display_name(), self._name.span())
+            .unwrap_or_else(|_| self._name.clone())
+    }
+
+    fn should_have_set_method(&self) -> bool {
+        if self.requiredarg {
+            return false;
+        }
+        match &self.vecattr {
+            Some(att) => att.method.unraw() != self._name.unraw(),
+            None => true,
+        }
+    }
+
+    /// The type of the setter's argument, which differs from `setter_ty` with
+    /// `setter(wrap)`.
+    fn setter_arg_ty(&self) -> TokenStream {
+        let ty = self.setter_ty();
+        match &self.setterwrap {
+            Some(ParsedSetterWrap::New(inner)) => quote! { #inner },
+            Some(ParsedSetterWrap::Into(_)) => quote! { impl ::std::convert::Into<#ty> },
+            Some(ParsedSetterWrap::PathBuf) => {
+                quote! { impl ::std::convert::AsRef<::std::path::Path> }
+            }
+            None => quote! { #ty },
+        }
+    }
+
+    /// Converts `arg`, the setter's argument, into a `setter_ty`.
+    fn setter_arg_value(&self, arg: &Ident) -> TokenStream {
+        let ty = self.setter_ty();
+        match &self.setterwrap {
+            Some(ParsedSetterWrap::New(_)) => quote! { <#ty>::new(#arg) },
+            Some(ParsedSetterWrap::Into(_)) => quote! { ::std::convert::Into::into(#arg) },
+            Some(ParsedSetterWrap::PathBuf) => quote! {
+                ::std::path::Path::to_path_buf(
+                    ::std::convert::AsRef::<::std::path::Path>::as_ref(&#arg)
+                )
+            },
+            None => quote! { #arg },
+        }
+    }
+
+    fn has_vec_attribute(&self) -> bool {
+        self.vecattr.is_some()
+    }
+
+    /// Whether the field has to be given for the build to succeed, either to
+    /// `builder()` or through its setter.
+    fn is_required(&self) -> bool {
+        self.requiredarg
+            || (self.storageattr.is_none()
+                && self.defaultattr.is_none()
+                && self.vecattr.is_none()
+                && !is_option_type(self.ty()))
+    }
+}
+
+impl<'a> TryFrom<&'a Field> for ParsedField<'a> {
+    fn try_from(field: &'a Field) -> Result<Self, Self::Error> {
+        ParsedField::new(field)
+    }
+
+    type Error = syn::Error;
+}
+
+/// How `#[builder(setter(wrap))]` turns the argument of a setter into the
+/// value stored in the builder, depending on the type of the field.
+#[derive(Clone)]
+enum ParsedSetterWrap {
+    /// `Box<T>`, `Arc<T>` or `Rc<T>`: the setter takes the `T` and passes it
+    /// to the pointer's `new`.
+    New(Type),
+    /// `Cow<'a, B>`, or a pointer to an unsized `B` such as `Arc<str>`: the
+    /// setter takes anything that converts into the field's type, such as a
+    /// `&B` or a `B::Owned`.
+    Into(Type),
+    /// `PathBuf`: the setter takes anything that can be viewed as a `Path`.
+    PathBuf,
+}
+
+impl ParsedSetterWrap {
+    /// `ty` is the type taken by the setter without the option, which is the
+    /// field's type without any `Option`.
+    fn new(ty: &Type, option: &Path) -> Result<Self, syn::Error> {
+        for pointer in ["Box", "Arc", "Rc"] {
+            let inner = match get_container_type_inner(ty, pointer) {
+                Some(inner) => inner,
+                None => continue,
+            };
+            return match inner {
+                Type::TraitObject(_) => {
+                    let mut err = syn::Error::new(
+                        option.span(),
+                        format!(
+                            "`setter(wrap)` cannot wrap a value into a `{}` of a trait object, \
+                             since the concrete type is not known; remove it to set the `{}` \
+                             as a whole",
+                            pointer,
+                            get_type_text(ty)
+                        ),
+                    );
+                    err.combine(syn::Error::new(inner.span(), "trait object given here"));
+                    Err(err)
+                }
+                Type::Slice(_) => Ok(Self::Into(inner.clone())),
+                Type::Path(path)
+                    if path.qself.is_none()
+                        && ["str", "Path", "OsStr", "CStr"]
+                            .iter()
+                            .any(|name| path.path.is_ident(name)) =>
+                {
+                    Ok(Self::Into(inner.clone()))
+                }
+                _ => Ok(Self::New(inner.clone())),
+            };
+        }
+        if let Some(inner) = get_container_type_inner(ty, "Cow") {
+            return Ok(Self::Into(inner.clone()));
+        }
+        if let Type::Path(path) = ty {
+            if path.qself.is_none()
+                && path
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|it| it.ident == "PathBuf" && it.arguments.is_empty())
+            {
+                return Ok(Self::PathBuf);
+            }
+        }
+        let mut err = syn::Error::new(
+            option.span(),
+            "`setter(wrap)` needs a field of type `Box<T>`, `Arc<T>`, `Rc<T>`, `Cow<'a, B>` or \
+             `PathBuf`, or an `Option` of one",
+        );
+        err.combine(syn::Error::new(ty.span(), "field type given here"));
+        Err(err)
+    }
+}
+
+/// Parses `setter(wrap)`, returning the `wrap` option.
+fn parse_setter_options(metalist: &MetaList) -> Result<Path, syn::Error> {
+    match metalist.nested.iter().collect::<Vec<_>>().as_slice() {
+        [NestedMeta::Meta(Meta::Path(path))] if path.is_ident("wrap") => Ok(path.clone()),
+        _ => Err(syn::Error::new(metalist.span(), "expected `setter(wrap)`")),
+    }
+}
+
+#[derive(Debug, Clone)]
+
+struct ParsedVecAttribute {
+    method: Ident,
+}
+
+impl ParsedVecAttribute {
+    fn new(method_name: &LitStr) -> Result<Self, syn::Error> {
+        let name = method_name.value();
+        let name = name.strip_prefix("r#").unwrap_or(&name);
+        let method = ident_from_name(name, method_name.span())?;
+        Ok(Self { method })
+    }
+}
+
+/// Turns a name given as a string into an identifier, using a raw identifier
+/// if the name is a keyword such as `type`.
+fn ident_from_name(name: &str, span: Span) -> Result<Ident, syn::Error> {
+    let mut ident = syn::parse_str::<Ident>(name)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{}", name)))
+        .map_err(|_| {
+            syn::Error::new(span, format!("`{}` cannot be used as a method name", name))
+        })?;
+    ident.set_span(span);
+    Ok(ident)
+}
+
+#[derive(Clone)]
+struct ParsedStorageAttribute {
+    ty: Option<Type>,
+    build: Option<Expr>,
+    buildtext: Option<String>,
+}
+
+impl ParsedStorageAttribute {
+    fn new(metalist: &MetaList) -> Result<Self, syn::Error> {
+        let err = |span| syn::Error::new(span, "expected `field(ty = \"...\", build = \"...\")`");
+        let mut parsed = Self {
+            ty: None,
+            build: None,
+            buildtext: None,
+        };
+        for item in metalist.nested.iter() {
+            match item {
+                NestedMeta::Meta(Meta::NameValue(value)) => match &value.lit {
+                    Lit::Str(lit) if value.path.is_ident("ty") => parsed.ty = Some(lit.parse()?),
+                    Lit::Str(lit) if value.path.is_ident("build") => {
+                        parsed.build = Some(lit.parse()?);
+                        parsed.buildtext = Some(lit.value());
+                    }
+                    _ => return Err(err(item.span())),
+                },
+                _ => return Err(err(item.span())),
+            }
+        }
+        if parsed.ty.is_none() && parsed.build.is_none() {
+            return Err(err(metalist.span()));
+        }
+        Ok(parsed)
+    }
+}
+
+#[derive(Clone)]
+struct ParsedDefaultAttribute {
+    expr: Expr,
+    text: String,
+    asynchronous: bool,
+    /// Whether this is a bare `default`, without an expression of its own.
+    fromtrait: bool,
+}
+
+impl ParsedDefaultAttribute {
+    /// `default = "expr"` gives the default as an expression in a string,
+    /// while any other literal, as in `default = 30`, is the default itself.
+    fn new(lit: &Lit) -> Result<Self, syn::Error> {
+        if let Lit::Str(lit) = lit {
+            Ok(Self {
+                expr: lit.parse()?,
+                text: lit.value(),
+                asynchronous: false,
+                fromtrait: false,
+            })
+        } else {
+            Ok(Self {
+                expr: syn::parse_quote!(#lit),
+                text: quote!(#lit).to_string(),
+                asynchronous: false,
+                fromtrait: false,
+            })
+        }
+    }
+
+    /// `default_async = "expr"` gives an expression whose value is awaited.
+    fn new_async(lit: &Lit) -> Result<Self, syn::Error> {
+        if let Lit::Str(lit) = lit {
+            Ok(Self {
+                expr: lit.parse()?,
+                text: format!("{}.await", lit.value()),
+                asynchronous: true,
+                fromtrait: false,
+            })
+        } else {
+            Err(syn::Error::new(
+                lit.span(),
+                "expected an expression in a string, e.g. `default_async = \"fetch()\"`",
+            ))
+        }
+    }
+
+    fn value(&self) -> TokenStream {
+        let expr = &self.expr;
+        if self.asynchronous {
+            quote! { (#expr).await }
+        } else {
+            quote! { #expr }
+        }
+    }
+
+    /// A bare `default` uses the field type's `Default` implementation.
+    fn default_trait(path: &Path) -> Self {
+        Self {
+            expr: syn::parse_quote_spanned!(path.span()=> ::std::default::Default::default()),
+            text: "Default::default()".to_owned(),
+            asynchronous: false,
+            fromtrait: true,
+        }
+    }
+}
+
+/// A constraint on the value of a field, checked by the build method.
+#[derive(Clone)]
+enum ParsedConstraint {
+    /// `range(min = ..., max = ...)`, where either bound may be left out.
+    Range {
+        min: Option<ParsedBound>,
+        max: Option<ParsedBound>,
+        span: Span,
+    },
+    /// `non_empty`, for any type with an `is_empty` method.
+    NonEmpty(Path),
+    /// `regex = "..."`, for any type that is `AsRef<str>`.
+    Regex(LitStr),
+    /// `validate = "path"`, naming a `fn(&T) -> Result<(), E>` where `E`
+    /// implements `Display`.
+    Validate(Path),
+}
+
+/// A bound of `range`, with its text as written so that messages do not show
+/// the spacing of the parsed tokens.
+#[derive(Clone)]
+struct ParsedBound {
+    expr: Box<Expr>,
+    text: String,
+}
+
+impl ParsedConstraint {
+    fn validate(lit: &Lit) -> Result<Self, syn::Error> {
+        match lit {
+            Lit::Str(lit) => Ok(ParsedConstraint::Validate(lit.parse()?)),
+            _ => Err(syn::Error::new(
+                lit.span(),
+                "expected a function path in a string, e.g. `validate = \"check\"`",
+            )),
+        }
+    }
+
+    fn regex(lit: &Lit) -> Result<Self, syn::Error> {
+        let pattern = match lit {
+            Lit::Str(lit) => lit,
+            _ => {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "expected a pattern in a string, e.g. `regex = \"^[a-z]+$\"`",
+                ))
+            }
+        };
+        if !cfg!(feature = "regex") {
+            return Err(syn::Error::new(
+                pattern.span(),
+                "`regex` needs the `regex` feature of derive_builder, \
+                 and a dependency on the regex crate",
+            ));
+        }
+        check_regex_pattern(pattern)?;
+        Ok(ParsedConstraint::Regex(pattern.clone()))
+    }
+
+    fn range(metalist: &MetaList) -> Result<Self, syn::Error> {
+        let err = |span| {
+            syn::Error::new(
+                span,
+                "expected `range(min = ..., max = ...)` with a number or an expression \
+                 in a string for each bound",
+            )
+        };
+        let mut min = None;
+        let mut max = None;
+        for item in metalist.nested.iter() {
+            let bound = match item {
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("min") => &mut min,
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("max") => &mut max,
+                _ => return Err(err(item.span())),
+            };
+            let lit = match item {
+                NestedMeta::Meta(Meta::NameValue(value)) => &value.lit,
+                _ => unreachable!(),
+            };
+            *bound = Some(match lit {
+                Lit::Int(_) | Lit::Float(_) => ParsedBound {
+                    expr: Box::new(syn::parse_quote!(#lit)),
+                    text: quote!(#lit).to_string(),
+                },
+                Lit::Str(lit) => ParsedBound {
+                    expr: Box::new(lit.parse()?),
+                    text: lit.value(),
+                },
+                _ => return Err(err(lit.span())),
+            });
+        }
+        if min.is_none() && max.is_none() {
+            return Err(err(metalist.span()));
+        }
+        Ok(ParsedConstraint::Range {
+            min,
+            max,
+            span: metalist.span(),
+        })
+    }
+
+    /// The constraint as written in the attribute, for use in errors and
+    /// documentation.
+    fn text(&self) -> String {
+        match self {
+            ParsedConstraint::Range { min, max, .. } => {
+                let bounds: Vec<_> = [("min", min), ("max", max)]
+                    .into_iter()
+                    .filter_map(|(name, bound)| {
+                        bound
+                            .as_ref()
+                            .map(|bound| format!("{} = {}", name, bound.text))
+                    })
+                    .collect();
+                format!("range({})", bounds.join(", "))
+            }
+            ParsedConstraint::NonEmpty(_) => "non_empty".to_owned(),
+            ParsedConstraint::Regex(pattern) => format!("regex = {:?}", pattern.value()),
+            ParsedConstraint::Validate(path) => format!(
+                "validate = \"{}\"",
+                quote!(#path).to_string().replace(' ', "")
+            ),
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            ParsedConstraint::Range { span, .. } => *span,
+            ParsedConstraint::NonEmpty(path) => path.span(),
+            ParsedConstraint::Regex(pattern) => pattern.span(),
+            ParsedConstraint::Validate(path) => path.span(),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+fn check_regex_pattern(pattern: &LitStr) -> Result<(), syn::Error> {
+    match regex::Regex::new(&pattern.value()) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(syn::Error::new(
+            pattern.span(),
+            format!("invalid pattern: {}", err),
+        )),
+    }
+}
+
+#[cfg(not(feature = "regex"))]
+fn check_regex_pattern(_: &LitStr) -> Result<(), syn::Error> {
+    Ok(())
+}
+
+/// A struct-level constraint on which fields are set, checked by the build
+/// method.
+enum ParsedFieldRelation {
+    /// `one_of(a, b, ...)`: exactly one of the fields must be set.
+    OneOf(Vec<Ident>),
+    /// `requires(a, b, ...)`: if `a` is set, all the other fields must be set.
+    Requires(Vec<Ident>),
+    /// `conflicts(a, b, ...)`: at most one of the fields may be set.
+    Conflicts(Vec<Ident>),
+}
+
+impl ParsedFieldRelation {
+    fn new(metalist: &MetaList) -> Result<Self, syn::Error> {
+        let keyword = metalist.path.get_ident().map(Ident::to_string);
+        let err = |span| {
+            syn::Error::new(
+                span,
+                format!(
+                    "expected two or more field names, e.g. `{}(username, password)`",
+                    keyword.as_deref().unwrap_or_default()
+                ),
+            )
+        };
+        let fields = metalist
+            .nested
+            .iter()
+            .map(|nested| {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) => path.get_ident().cloned(),
+                    _ => None,
+                }
+                .ok_or_else(|| err(nested.span()))
+            })
+            .collect::<Result<Vec<Ident>, syn::Error>>()?;
+        if fields.len() < 2 {
+            return Err(err(metalist.span()));
+        }
+        if let Some((_, name)) = fields
+            .iter()
+            .enumerate()
+            .find(|(index, name)| fields[..*index].contains(name))
+        {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("`{}` is listed twice", name.unraw()),
+            ));
+        }
+        Ok(match keyword.as_deref() {
+            Some("one_of") => ParsedFieldRelation::OneOf(fields),
+            Some("requires") => ParsedFieldRelation::Requires(fields),
+            _ => ParsedFieldRelation::Conflicts(fields),
+        })
+    }
+
+    fn fields(&self) -> &[Ident] {
+        match self {
+            ParsedFieldRelation::OneOf(fields)
+            | ParsedFieldRelation::Requires(fields)
+            | ParsedFieldRelation::Conflicts(fields) => fields,
+        }
+    }
+
+    fn keyword(&self) -> &'static str {
+        match self {
+            ParsedFieldRelation::OneOf(_) => "one_of",
+            ParsedFieldRelation::Requires(_) => "requires",
+            ParsedFieldRelation::Conflicts(_) => "conflicts",
+        }
+    }
+
+    /// Describes the constraint for the documentation of the build method.
+    fn describe(&self) -> String {
+        let names: Vec<String> = self
+            .fields()
+            .iter()
+            .map(|name| format!("`{}`", name.unraw()))
+            .collect();
+        match self {
+            ParsedFieldRelation::OneOf(_) => {
+                format!("exactly one of {} must be set", names.join(", "))
+            }
+            ParsedFieldRelation::Requires(_) => format!(
+                "if {} is set, {} must be set too",
+                names[0],
+                names[1..].join(", ")
+            ),
+            ParsedFieldRelation::Conflicts(_) => {
+                format!("at most one of {} may be set", names.join(", "))
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct ParsedStructAttributes {
+    derives: Vec<Path>,
+    structattrs: Vec<Meta>,
+    constbuilder: bool,
+    asyncbuild: bool,
+    validate: Option<Path>,
+    patch: bool,
+    relations: Vec<ParsedFieldRelation>,
+    tracksource: bool,
+    cli: bool,
+}
+
+const STRUCT_OPTIONS: &[&str] = &[
+    "derive(...)",
+    "struct_attr(...)",
+    "const",
+    "build_fn(...)",
+    "patch",
+    "track_source",
+    "cli",
+    "one_of(...)",
+    "requires(...)",
+    "conflicts(...)",
+];
+
+impl ParsedStructAttributes {
+    fn new(attrs: &[Attribute]) -> Result<Self, syn::Error> {
+        let mut parsed = Self::default();
+        for attr in attrs.iter().filter(|attr| is_builder_attribute(attr)) {
+            let err = || {
+                syn::Error::new(
+                    attr.tokens.span(),
+                    format!(
+                        "expected `builder(...)` with one of: `{}`",
+                        STRUCT_OPTIONS.join("`, `")
+                    ),
+                )
+            };
+            for item in parse_builder_attribute_items(attr, err)? {
+                match &item {
+                    NestedMeta::Meta(Meta::List(metalist)) if metalist.path.is_ident("derive") => {
+                        for derive in parse_forwarded_attributes(metalist)? {
+                            if let Meta::Path(path) = derive {
+                                parsed.derives.push(path);
+                            } else {
+                                return Err(syn::Error::new(
+                                    derive.span(),
+                                    "expected a trait name, e.g. `derive(Debug, Clone)`",
+                                ));
+                            }
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("const") => {
+                        parsed.constbuilder = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("patch") => {
+                        parsed.patch = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("track_source") => {
+                        parsed.tracksource = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("cli") => {
+                        if !cfg!(feature = "cli") {
+                            return Err(syn::Error::new(
+                                path.span(),
+                                "`cli` needs the `cli` feature of derive_builder",
+                            ));
+                        }
+                        parsed.cli = true;
+                    }
+                    NestedMeta::Meta(Meta::List(metalist))
+                        if metalist.path.is_ident("build_fn") =>
+                    {
+                        parsed.parse_build_fn(metalist)?;
+                    }
+                    NestedMeta::Meta(Meta::List(metalist))
+                        if metalist.path.is_ident("one_of")
+                            || metalist.path.is_ident("requires")
+                            || metalist.path.is_ident("conflicts") =>
+                    {
+                        parsed.relations.push(ParsedFieldRelation::new(metalist)?);
+                    }
+                    NestedMeta::Meta(Meta::List(metalist))
+                        if metalist.path.is_ident("struct_attr") =>
+                    {
+                        parsed
+                            .structattrs
+                            .extend(parse_forwarded_attributes(metalist)?);
+                    }
+                    _ => return Err(err()),
+                }
+            }
+        }
+        if parsed.constbuilder && parsed.asyncbuild {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`const` and `build_fn(async)` cannot be used together",
+            ));
+        }
+        if parsed.constbuilder && parsed.tracksource {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`const` and `track_source` cannot be used together",
+            ));
+        }
+        if parsed.constbuilder && parsed.cli {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`const` and `cli` cannot be used together",
+            ));
+        }
+        Ok(parsed)
+    }
+
+    fn parse_build_fn(&mut self, metalist: &MetaList) -> Result<(), syn::Error> {
+        for item in metalist.nested.iter() {
+            match item {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("async") => {
+                    self.asyncbuild = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("validate") => {
+                    if let Lit::Str(lit) = &value.lit {
+                        self.validate = Some(lit.parse()?);
+                    } else {
+                        return Err(syn::Error::new(
+                            value.lit.span(),
+                            "expected a function path in a string, e.g. `validate = \"check\"`",
+                        ));
+                    }
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        item.span(),
+                        "expected `build_fn(async)` or `build_fn(validate = \"...\")`",
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_builder_attribute(attr: &Attribute) -> bool {
+    attr.path
+        .segments
+        .iter()
+        .last()
+        .is_some_and(|seg| seg.ident == "builder")
+}
+
+fn parse_builder_attribute_items(
+    attr: &Attribute,
+    err: impl Fn() -> syn::Error,
+) -> Result<Vec<NestedMeta>, syn::Error> {
+    if let Meta::List(metalist) = attr.parse_meta()? {
+        Ok(metalist.nested.into_iter().collect())
+    } else {
+        Err(err())
+    }
+}
+
+/// Returns the part of a field's `#[cfg]` or `#[cfg_attr]` attribute that
+/// decides whether the field exists, so it can be repeated on every piece of
+/// generated code that refers to the field.
+///
+/// A `#[cfg_attr(predicate, ...)]` only affects the field's existence through
+/// any `cfg(...)` it expands to; other attributes it carries (e.g. serde
+/// helpers) are meaningless on the builder and are dropped.
+fn parse_cfg_attribute(attr: &Attribute) -> Result<Option<TokenStream>, syn::Error> {
+    if attr.path.is_ident("cfg") {
+        return Ok(Some(quote! { #attr }));
+    }
+    if !attr.path.is_ident("cfg_attr") {
+        return Ok(None);
+    }
+    let err = || syn::Error::new(attr.tokens.span(), "expected `cfg_attr(predicate, ...)`");
+    let metalist = match attr.parse_meta()? {
+        Meta::List(metalist) => metalist,
+        _ => return Err(err()),
+    };
+    let mut nested = metalist.nested.iter();
+    let predicate = nested.next().ok_or_else(err)?;
+    let cfgs: Vec<_> = nested
+        .filter(|it| matches!(it, NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("cfg")))
+        .collect();
+    if cfgs.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(quote! { #[cfg_attr(#predicate, #(#cfgs),*)] }))
+    }
+}
+
+fn parse_forwarded_attributes(metalist: &MetaList) -> Result<Vec<Meta>, syn::Error> {
+    metalist
+        .nested
+        .iter()
+        .map(|nested| match nested {
+            NestedMeta::Meta(meta) => Ok(meta.clone()),
+            NestedMeta::Lit(lit) => Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "expected an attribute, e.g. `{}(doc(hidden))`",
+                    metalist
+                        .path
+                        .get_ident()
+                        .map_or_else(String::new, Ident::to_string)
+                ),
+            )),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_derive_code, generate_fn_builder_code, parse_fn_builder_args};
+    use std::fs;
+    use std::path::Path;
+
+    /// Compares the expansion of every `tests/expand/*.rs` input with the
+    /// golden `*.expanded.rs` file next to it. Run with `EXPAND=overwrite` to
+    /// write the current expansions as the new golden files.
+    #[test]
+    fn expansions_match_golden_files() {
+        let overwrite = std::env::var("EXPAND").is_ok_and(|it| it == "overwrite");
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/expand");
+        let mut inputs: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                let name = path.file_name().unwrap().to_str().unwrap();
+                name.ends_with(".rs") && !name.ends_with(".expanded.rs")
+            })
+            .collect();
+        inputs.sort();
+        assert!(!inputs.is_empty(), "no inputs found in {}", dir.display());
+
+        let mut mismatches = Vec::new();
+        for input in inputs {
+            let source = fs::read_to_string(&input).unwrap();
+            if !required_feature_enabled(&source) {
+                continue;
+            }
+            let file: syn::File = syn::parse_str(&source).unwrap();
+            let mut expanded = Vec::new();
+            for item in file.items {
+                let tokens = match item {
+                    syn::Item::Struct(item) => generate_derive_code(&syn::DeriveInput::from(item)),
+                    syn::Item::Fn(mut item) => {
+                        let attr = item.attrs.remove(0);
+                        parse_fn_builder_args(&attr)
+                            .and_then(|args| generate_fn_builder_code(args, syn::Item::Fn(item)))
+                    }
+                    syn::Item::Impl(mut item) => {
+                        let attr = item.attrs.remove(0);
+                        parse_fn_builder_args(&attr)
+                            .and_then(|args| generate_fn_builder_code(args, syn::Item::Impl(item)))
+                    }
+                    _ => continue,
+                };
+                let tokens = tokens.unwrap_or_else(|err| err.to_compile_error());
+                expanded.push(prettyplease::unparse(&syn::parse2(tokens).unwrap()));
+            }
+            let actual = expanded.join("\n");
+            let golden = input.with_extension("expanded.rs");
+            if overwrite {
+                fs::write(&golden, &actual).unwrap();
+            } else {
+                let expected = fs::read_to_string(&golden).unwrap_or_default();
+                if let Some(diff) = first_difference(&expected, &actual) {
+                    mismatches.push(format!("{}:{}", golden.display(), diff));
+                }
+            }
+        }
+        assert!(
+            mismatches.is_empty(),
+            "expansion differs from golden file(s), rerun with EXPAND=overwrite and review \
+             the diff:\n{}",
+            mismatches.join("\n")
+        );
+    }
+
+    /// An input whose first line is `// feature: <name>` uses an option that
+    /// is rejected without that feature, so it is only expanded with it.
+    fn required_feature_enabled(source: &str) -> bool {
+        let line = source.lines().next().unwrap_or_default();
+        match line.strip_prefix("// feature: ") {
+            Some("cli") => cfg!(feature = "cli"),
+            Some("regex") => cfg!(feature = "regex"),
+            Some(other) => panic!("unknown feature `{}`", other),
+            None => true,
+        }
+    }
+
+    fn first_difference(expected: &str, actual: &str) -> Option<String> {
+        let mut expected = expected.lines();
+        let mut actual = actual.lines();
+        let mut line = 1;
+        loop {
+            match (expected.next(), actual.next()) {
+                (None, None) => return None,
+                (left, right) if left != right => {
+                    return Some(format!(
+                        "{}\n- {}\n+ {}",
+                        line,
+                        left.unwrap_or("<end of file>"),
+                        right.unwrap_or("<end of file>")
+                    ))
+                }
+                _ => line += 1,
+            }
+        }
+    }
+}
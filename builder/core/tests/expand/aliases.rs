@@ -0,0 +1,7 @@
+#[derive(Builder)]
+pub struct Connection {
+    #[builder(alias = "hostname", deprecated = "use `host` instead")]
+    host: String,
+    #[builder(alias = "timeout")]
+    timeout_seconds: Option<u64>,
+}
@@ -0,0 +1,174 @@
+impl Config {
+    ///Creates a [`ConfigBuilder`] with no fields set, to construct a new [`Config`].
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+}
+/**A builder for [`Config`].
+
+Create one with [`Config::builder`], call the setters for the fields you want to set and finish with [`ConfigBuilder::build`].*/
+pub struct ConfigBuilder {
+    host: ::std::option::Option<String>,
+    port: ::std::option::Option<u16>,
+}
+///An error returned by [`ConfigBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ConfigBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ConfigBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ConfigBuilderError {}
+///Describes a field set by a [`ConfigBuilder`], see [`ConfigBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ConfigBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl ConfigBuilder {
+    ///Describes the fields of [`Config`], in the order they are declared.
+    pub const FIELDS: &'static [ConfigBuilderFieldInfo] = &[
+        ConfigBuilderFieldInfo {
+            name: "host",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ConfigBuilderFieldInfo {
+            name: "port",
+            ty: "u16",
+            required: false,
+            default: ::std::option::Option::Some("default_port().await"),
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        ConfigBuilder {
+            host: ::std::option::Option::None,
+            port: ::std::option::Option::None,
+        }
+    }
+    ///Sets `host`.
+    pub fn host(&mut self, host: String) -> &mut Self {
+        self.host = ::std::option::Option::Some(host);
+        self
+    }
+    ///Sets `port`.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = ::std::option::Option::Some(port);
+        self
+    }
+    ///Builds a new [`Config`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `host`
+    ///
+    ///Returns an error if `check_config` rejects the built value.
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `port`: `default_port().await`
+    pub async fn build(&mut self) -> ::std::result::Result<Config, ConfigBuilderError> {
+        let host = match &self.host {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConfigBuilderError::MissingField {
+                        field: "host",
+                    }),
+                );
+            }
+        };
+        let port = match &self.port {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => (default_port()).await,
+        };
+        let value = Config { host: host, port: port };
+        if let ::std::result::Result::Err(err) = check_config(&value).await {
+            return ::std::result::Result::Err(
+                ConfigBuilderError::Custom(::std::convert::Into::into(err)),
+            );
+        }
+        ::std::result::Result::Ok(value)
+    }
+}
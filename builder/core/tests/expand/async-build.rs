@@ -0,0 +1,7 @@
+#[derive(Builder)]
+#[builder(build_fn(async, validate = "check_config"))]
+pub struct Config {
+    host: String,
+    #[builder(default_async = "default_port()")]
+    port: u16,
+}
@@ -0,0 +1,223 @@
+impl Connection {
+    ///Creates a [`ConnectionBuilder`] with no fields set, to construct a new [`Connection`].
+    pub fn builder() -> ConnectionBuilder {
+        ConnectionBuilder::new()
+    }
+}
+/**A builder for [`Connection`].
+
+Create one with [`Connection::builder`], call the setters for the fields you want to set and finish with [`ConnectionBuilder::build`].*/
+pub struct ConnectionBuilder {
+    host: ::std::option::Option<String>,
+    #[cfg(feature = "tls")]
+    certificate: ::std::option::Option<String>,
+    #[cfg_attr(feature = "proxy", cfg(all()))]
+    proxies: ::std::option::Option<Vec<String>>,
+}
+///An error returned by [`ConnectionBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ConnectionBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ConnectionBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ConnectionBuilderError {}
+///Describes a field set by a [`ConnectionBuilder`], see [`ConnectionBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ConnectionBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl ConnectionBuilder {
+    ///Describes the fields of [`Connection`], in the order they are declared.
+    pub const FIELDS: &'static [ConnectionBuilderFieldInfo] = &[
+        ConnectionBuilderFieldInfo {
+            name: "host",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        #[cfg(feature = "tls")]
+        ConnectionBuilderFieldInfo {
+            name: "certificate",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        #[cfg_attr(feature = "proxy", cfg(all()))]
+        ConnectionBuilderFieldInfo {
+            name: "proxies",
+            ty: "Vec<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::Some("proxy"),
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        ConnectionBuilder {
+            host: ::std::option::Option::None,
+            #[cfg(feature = "tls")]
+            certificate: ::std::option::Option::None,
+            #[cfg_attr(feature = "proxy", cfg(all()))]
+            proxies: ::std::option::Option::Some(::std::vec::Vec::new()),
+        }
+    }
+    ///Sets `host`.
+    pub fn host(&mut self, host: String) -> &mut Self {
+        self.host = ::std::option::Option::Some(host);
+        self
+    }
+    ///Sets `certificate`.
+    #[cfg(feature = "tls")]
+    pub fn certificate(&mut self, certificate: String) -> &mut Self {
+        self.certificate = ::std::option::Option::Some(certificate);
+        self
+    }
+    ///Sets `proxies`.
+    #[cfg_attr(feature = "proxy", cfg(all()))]
+    pub fn proxies(&mut self, proxies: Vec<String>) -> &mut Self {
+        self.proxies = ::std::option::Option::Some(proxies);
+        self
+    }
+    ///Appends a single element to `proxies`.
+    #[cfg_attr(feature = "proxy", cfg(all()))]
+    pub fn proxy(&mut self, proxy: String) -> &mut Self {
+        self.proxies.get_or_insert_with(|| ::std::vec::Vec::new()).push(proxy);
+        self
+    }
+    ///Builds a new [`Connection`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `host`
+    ///- `certificate`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `proxies`: an empty `Vec`
+    pub fn build(
+        &mut self,
+    ) -> ::std::result::Result<Connection, ConnectionBuilderError> {
+        let host = match &self.host {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConnectionBuilderError::MissingField {
+                        field: "host",
+                    }),
+                );
+            }
+        };
+        #[cfg(feature = "tls")]
+        let certificate = match &self.certificate {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConnectionBuilderError::MissingField {
+                        field: "certificate",
+                    }),
+                );
+            }
+        };
+        #[cfg_attr(feature = "proxy", cfg(all()))]
+        let proxies = match &self.proxies {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConnectionBuilderError::MissingField {
+                        field: "proxies",
+                    }),
+                );
+            }
+        };
+        let value = Connection {
+            host: host,
+            #[cfg(feature = "tls")]
+            certificate: certificate,
+            #[cfg_attr(feature = "proxy", cfg(all()))]
+            proxies: proxies,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
@@ -0,0 +1,9 @@
+#[derive(Builder)]
+pub struct Connection {
+    host: String,
+    #[cfg(feature = "tls")]
+    certificate: String,
+    #[cfg_attr(feature = "proxy", cfg(all()), allow(dead_code))]
+    #[builder(each = "proxy")]
+    proxies: Vec<String>,
+}
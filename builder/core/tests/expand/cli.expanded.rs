@@ -0,0 +1,495 @@
+impl Config {
+    ///Creates a [`ConfigBuilder`] with no fields set, to construct a new [`Config`].
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+}
+/**A builder for [`Config`].
+
+Create one with [`Config::builder`], call the setters for the fields you want to set and finish with [`ConfigBuilder::build`].*/
+pub struct ConfigBuilder {
+    host: ::std::option::Option<String>,
+    port: ::std::option::Option<u16>,
+    verbose: ::std::option::Option<bool>,
+    routes: ::std::option::Option<Vec<String>>,
+    bucket: ::std::option::Option<Arc<str>>,
+}
+///An error returned by [`ConfigBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ConfigBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ConfigBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ConfigBuilderError {}
+///Describes a field set by a [`ConfigBuilder`], see [`ConfigBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ConfigBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl ConfigBuilder {
+    ///Describes the fields of [`Config`], in the order they are declared.
+    pub const FIELDS: &'static [ConfigBuilderFieldInfo] = &[
+        ConfigBuilderFieldInfo {
+            name: "host",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "Address to listen on.",
+        },
+        ConfigBuilderFieldInfo {
+            name: "port",
+            ty: "u16",
+            required: false,
+            default: ::std::option::Option::Some("8080"),
+            each: ::std::option::Option::None,
+            docs: "Port to listen on.",
+        },
+        ConfigBuilderFieldInfo {
+            name: "verbose",
+            ty: "bool",
+            required: false,
+            default: ::std::option::Option::Some("Default::default()"),
+            each: ::std::option::Option::None,
+            docs: "Print every request.",
+        },
+        ConfigBuilderFieldInfo {
+            name: "routes",
+            ty: "Vec<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::Some("route"),
+            docs: "Path to serve.",
+        },
+        ConfigBuilderFieldInfo {
+            name: "bucket",
+            ty: "Arc<str>",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        ConfigBuilder {
+            host: ::std::option::Option::None,
+            port: ::std::option::Option::None,
+            verbose: ::std::option::Option::None,
+            routes: ::std::option::Option::Some(::std::vec::Vec::new()),
+            bucket: ::std::option::Option::None,
+        }
+    }
+    /// Address to listen on.
+    pub fn host(&mut self, host: String) -> &mut Self {
+        self.host = ::std::option::Option::Some(host);
+        self
+    }
+    ///Deprecated alias of [`host`](Self::host).
+    #[deprecated(note = "renamed to `host`")]
+    pub fn bind(&mut self, host: String) -> &mut Self {
+        self.host(host)
+    }
+    /// Port to listen on.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = ::std::option::Option::Some(port);
+        self
+    }
+    /// Print every request.
+    pub fn verbose(&mut self, verbose: bool) -> &mut Self {
+        self.verbose = ::std::option::Option::Some(verbose);
+        self
+    }
+    /// Path to serve.
+    pub fn routes(&mut self, routes: Vec<String>) -> &mut Self {
+        self.routes = ::std::option::Option::Some(routes);
+        self
+    }
+    ///Sets `bucket`.
+    pub fn bucket(&mut self, bucket: impl ::std::convert::Into<Arc<str>>) -> &mut Self {
+        self.bucket = ::std::option::Option::Some(::std::convert::Into::into(bucket));
+        self
+    }
+    ///Appends a single element to `routes`.
+    ///
+    /// Path to serve.
+    pub fn route(&mut self, route: String) -> &mut Self {
+        self.routes.get_or_insert_with(|| ::std::vec::Vec::new()).push(route);
+        self
+    }
+    ///Builds a new [`Config`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `host`
+    ///- `bucket`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `port`: `8080`
+    ///- `verbose`: `Default::default()`
+    ///- `routes`: an empty `Vec`
+    pub fn build(&mut self) -> ::std::result::Result<Config, ConfigBuilderError> {
+        let host = match &self.host {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConfigBuilderError::MissingField {
+                        field: "host",
+                    }),
+                );
+            }
+        };
+        let port = match &self.port {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => 8080,
+        };
+        let verbose = match &self.verbose {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => ::std::default::Default::default(),
+        };
+        let routes = match &self.routes {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConfigBuilderError::MissingField {
+                        field: "routes",
+                    }),
+                );
+            }
+        };
+        let bucket = match &self.bucket {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConfigBuilderError::MissingField {
+                        field: "bucket",
+                    }),
+                );
+            }
+        };
+        let value = Config {
+            host: host,
+            port: port,
+            verbose: verbose,
+            routes: routes,
+            bucket: bucket,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
+///An error returned by [`ConfigBuilder::parse_args`].
+#[derive(::std::fmt::Debug, ::std::clone::Clone, ::std::cmp::PartialEq, ::std::cmp::Eq)]
+pub enum ConfigBuilderArgsError {
+    /// `--help` was given.
+    Help,
+    /// An argument is not one of the options, or not an option at all.
+    Unknown {
+        /// The argument as given.
+        argument: ::std::string::String,
+    },
+    /// An option that takes a value was given last, without one.
+    MissingValue {
+        /// The option, without the leading dashes.
+        option: &'static str,
+    },
+    /// The value of an option could not be parsed.
+    InvalidValue {
+        /// The option, without the leading dashes.
+        option: &'static str,
+        /// Why the value could not be parsed.
+        message: ::std::string::String,
+    },
+}
+impl ::std::fmt::Display for ConfigBuilderArgsError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Help => ::std::fmt::Formatter::write_str(f, "help requested"),
+            Self::Unknown { argument } => {
+                ::std::write!(f, "unknown argument `{}`", argument)
+            }
+            Self::MissingValue { option } => {
+                ::std::write!(f, "`--{}` needs a value", option)
+            }
+            Self::InvalidValue { option, message } => {
+                ::std::write!(f, "invalid value for `--{}`: {}", option, message)
+            }
+        }
+    }
+}
+impl ::std::error::Error for ConfigBuilderArgsError {}
+impl ConfigBuilder {
+    /**Sets the fields given by command-line arguments, such as `--port 80` or `--port=80`, by calling the setters of this builder.
+
+The arguments must not include the program name, so pass `std::env::args().skip(1)`. See [`ConfigBuilder::help`] for the accepted options.
+
+# Errors
+
+Returns an error for an unknown option, an option without a value, or a value its type cannot parse. `--help` returns [`ConfigBuilderArgsError::Help`], for the caller to print the help and exit.*/
+    pub fn parse_args(
+        &mut self,
+        args: impl ::std::iter::IntoIterator<
+            Item = impl ::std::convert::Into<::std::string::String>,
+        >,
+    ) -> ::std::result::Result<&mut Self, ConfigBuilderArgsError> {
+        let mut args = ::std::iter::Iterator::map(
+            ::std::iter::IntoIterator::into_iter(args),
+            ::std::convert::Into::<::std::string::String>::into,
+        );
+        while let ::std::option::Option::Some(arg)
+            = ::std::iter::Iterator::next(&mut args) {
+            let (name, value) = match arg.strip_prefix("--") {
+                ::std::option::Option::Some(name) => {
+                    match name.split_once('=') {
+                        ::std::option::Option::Some((name, value)) => {
+                            (
+                                ::std::borrow::ToOwned::to_owned(name),
+                                ::std::option::Option::Some(
+                                    ::std::borrow::ToOwned::to_owned(value),
+                                ),
+                            )
+                        }
+                        ::std::option::Option::None => {
+                            (
+                                ::std::borrow::ToOwned::to_owned(name),
+                                ::std::option::Option::None,
+                            )
+                        }
+                    }
+                }
+                ::std::option::Option::None => {
+                    return ::std::result::Result::Err(ConfigBuilderArgsError::Unknown {
+                        argument: arg,
+                    });
+                }
+            };
+            match name.as_str() {
+                "help" => return ::std::result::Result::Err(ConfigBuilderArgsError::Help),
+                "host" | "bind" => {
+                    let parsed = {
+                        let value = match value {
+                            ::std::option::Option::Some(value) => value,
+                            ::std::option::Option::None => {
+                                match ::std::iter::Iterator::next(&mut args) {
+                                    ::std::option::Option::Some(value) => value,
+                                    ::std::option::Option::None => {
+                                        return ::std::result::Result::Err(ConfigBuilderArgsError::MissingValue {
+                                            option: "host",
+                                        });
+                                    }
+                                }
+                            }
+                        };
+                        match <String as ::std::str::FromStr>::from_str(&value) {
+                            ::std::result::Result::Ok(parsed) => parsed,
+                            ::std::result::Result::Err(err) => {
+                                return ::std::result::Result::Err(ConfigBuilderArgsError::InvalidValue {
+                                    option: "host",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        }
+                    };
+                    self.host(parsed);
+                }
+                "port" => {
+                    let parsed = {
+                        let value = match value {
+                            ::std::option::Option::Some(value) => value,
+                            ::std::option::Option::None => {
+                                match ::std::iter::Iterator::next(&mut args) {
+                                    ::std::option::Option::Some(value) => value,
+                                    ::std::option::Option::None => {
+                                        return ::std::result::Result::Err(ConfigBuilderArgsError::MissingValue {
+                                            option: "port",
+                                        });
+                                    }
+                                }
+                            }
+                        };
+                        match <u16 as ::std::str::FromStr>::from_str(&value) {
+                            ::std::result::Result::Ok(parsed) => parsed,
+                            ::std::result::Result::Err(err) => {
+                                return ::std::result::Result::Err(ConfigBuilderArgsError::InvalidValue {
+                                    option: "port",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        }
+                    };
+                    self.port(parsed);
+                }
+                "verbose" => {
+                    let parsed = match value {
+                        ::std::option::Option::None => true,
+                        ::std::option::Option::Some(value) => {
+                            match value.as_str() {
+                                "true" => true,
+                                "false" => false,
+                                _ => {
+                                    return ::std::result::Result::Err(ConfigBuilderArgsError::InvalidValue {
+                                        option: "verbose",
+                                        message: ::std::string::String::from(
+                                            "expected `true` or `false`",
+                                        ),
+                                    });
+                                }
+                            }
+                        }
+                    };
+                    self.verbose(parsed);
+                }
+                "route" => {
+                    let parsed = {
+                        let value = match value {
+                            ::std::option::Option::Some(value) => value,
+                            ::std::option::Option::None => {
+                                match ::std::iter::Iterator::next(&mut args) {
+                                    ::std::option::Option::Some(value) => value,
+                                    ::std::option::Option::None => {
+                                        return ::std::result::Result::Err(ConfigBuilderArgsError::MissingValue {
+                                            option: "route",
+                                        });
+                                    }
+                                }
+                            }
+                        };
+                        match <String as ::std::str::FromStr>::from_str(&value) {
+                            ::std::result::Result::Ok(parsed) => parsed,
+                            ::std::result::Result::Err(err) => {
+                                return ::std::result::Result::Err(ConfigBuilderArgsError::InvalidValue {
+                                    option: "route",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        }
+                    };
+                    self.route(parsed);
+                }
+                "bucket" => {
+                    let parsed = {
+                        let value = match value {
+                            ::std::option::Option::Some(value) => value,
+                            ::std::option::Option::None => {
+                                match ::std::iter::Iterator::next(&mut args) {
+                                    ::std::option::Option::Some(value) => value,
+                                    ::std::option::Option::None => {
+                                        return ::std::result::Result::Err(ConfigBuilderArgsError::MissingValue {
+                                            option: "bucket",
+                                        });
+                                    }
+                                }
+                            }
+                        };
+                        match <::std::string::String as ::std::str::FromStr>::from_str(
+                            &value,
+                        ) {
+                            ::std::result::Result::Ok(parsed) => parsed,
+                            ::std::result::Result::Err(err) => {
+                                return ::std::result::Result::Err(ConfigBuilderArgsError::InvalidValue {
+                                    option: "bucket",
+                                    message: ::std::string::ToString::to_string(&err),
+                                });
+                            }
+                        }
+                    };
+                    self.bucket(parsed);
+                }
+                _ => {
+                    return ::std::result::Result::Err(ConfigBuilderArgsError::Unknown {
+                        argument: arg,
+                    });
+                }
+            }
+        }
+        ::std::result::Result::Ok(self)
+    }
+    /// Describes the options accepted by `parse_args`.
+    pub fn help() -> ::std::string::String {
+        let mut help = ::std::string::String::from("Options:\n");
+        help.push_str("  --host <String>    Address to listen on. [required]\n");
+        help.push_str("  --port <u16>       Port to listen on. [default: 8080]\n");
+        help.push_str("  --verbose          Print every request. [default]\n");
+        help.push_str("  --route <String>   Path to serve. [may be repeated]\n");
+        help.push_str("  --bucket <String>  [required]\n");
+        help.push_str("  --help             Prints this help.\n");
+        help
+    }
+}
@@ -0,0 +1,19 @@
+// feature: cli
+#[derive(Builder)]
+#[builder(cli)]
+pub struct Config {
+    /// Address to listen on.
+    #[builder(alias = "bind")]
+    host: String,
+    /// Port to listen on.
+    #[builder(default = 8080)]
+    port: u16,
+    /// Print every request.
+    #[builder(default)]
+    verbose: bool,
+    /// Path to serve.
+    #[builder(each = "route")]
+    routes: Vec<String>,
+    #[builder(setter(wrap))]
+    bucket: Arc<str>,
+}
@@ -0,0 +1,121 @@
+impl DriverConfig {
+    ///Creates a [`DriverConfigBuilder`] with no fields set, to construct a new [`DriverConfig`].
+    pub const fn builder() -> DriverConfigBuilder {
+        DriverConfigBuilder::new()
+    }
+}
+/**A builder for [`DriverConfig`].
+
+Create one with [`DriverConfig::builder`], call the setters for the fields you want to set and finish with [`DriverConfigBuilder::build`].*/
+pub struct DriverConfigBuilder {
+    name: ::std::option::Option<&'static str>,
+    irq: ::std::option::Option<u8>,
+    dma_channel: ::std::option::Option<u8>,
+}
+///Describes a field set by a [`DriverConfigBuilder`], see [`DriverConfigBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct DriverConfigBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl DriverConfigBuilder {
+    ///Describes the fields of [`DriverConfig`], in the order they are declared.
+    pub const FIELDS: &'static [DriverConfigBuilderFieldInfo] = &[
+        DriverConfigBuilderFieldInfo {
+            name: "name",
+            ty: "&'static str",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        DriverConfigBuilderFieldInfo {
+            name: "irq",
+            ty: "u8",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        DriverConfigBuilderFieldInfo {
+            name: "dma_channel",
+            ty: "Option<u8>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub const fn new() -> Self {
+        DriverConfigBuilder {
+            name: ::std::option::Option::None,
+            irq: ::std::option::Option::None,
+            dma_channel: ::std::option::Option::None,
+        }
+    }
+    ///Sets `name`.
+    pub const fn name(mut self, name: &'static str) -> Self {
+        self.name = ::std::option::Option::Some(name);
+        self
+    }
+    ///Sets `irq`.
+    pub const fn irq(mut self, irq: u8) -> Self {
+        self.irq = ::std::option::Option::Some(irq);
+        self
+    }
+    ///Sets `dma_channel`.
+    pub const fn dma_channel(mut self, dma_channel: u8) -> Self {
+        self.dma_channel = ::std::option::Option::Some(dma_channel);
+        self
+    }
+    ///Builds a new [`DriverConfig`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `name`
+    ///- `irq`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `dma_channel`: `None`
+    pub const fn build(self) -> ::std::result::Result<DriverConfig, &'static str> {
+        let name = match self.name {
+            ::std::option::Option::Some(inner) => inner,
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err("name must be set");
+            }
+        };
+        let irq = match self.irq {
+            ::std::option::Option::Some(inner) => inner,
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err("irq must be set");
+            }
+        };
+        let dma_channel = self.dma_channel;
+        ::std::result::Result::Ok(DriverConfig {
+            name: name,
+            irq: irq,
+            dma_channel: dma_channel,
+        })
+    }
+}
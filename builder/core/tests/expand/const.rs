@@ -0,0 +1,7 @@
+#[derive(Builder)]
+#[builder(const)]
+pub struct DriverConfig {
+    name: &'static str,
+    irq: u8,
+    dma_channel: Option<u8>,
+}
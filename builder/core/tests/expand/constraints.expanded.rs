@@ -0,0 +1,211 @@
+impl Account {
+    ///Creates a [`AccountBuilder`] with no fields set, to construct a new [`Account`].
+    pub fn builder() -> AccountBuilder {
+        AccountBuilder::new()
+    }
+}
+/**A builder for [`Account`].
+
+Create one with [`Account::builder`], call the setters for the fields you want to set and finish with [`AccountBuilder::build`].*/
+pub struct AccountBuilder {
+    name: ::std::option::Option<String>,
+    port: ::std::option::Option<u16>,
+}
+///An error returned by [`AccountBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum AccountBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for AccountBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for AccountBuilderError {}
+///Describes a field set by a [`AccountBuilder`], see [`AccountBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct AccountBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl AccountBuilder {
+    ///Describes the fields of [`Account`], in the order they are declared.
+    pub const FIELDS: &'static [AccountBuilderFieldInfo] = &[
+        AccountBuilderFieldInfo {
+            name: "name",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        AccountBuilderFieldInfo {
+            name: "port",
+            ty: "Option<u16>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        AccountBuilder {
+            name: ::std::option::Option::None,
+            port: ::std::option::Option::None,
+        }
+    }
+    ///Sets `name`.
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = ::std::option::Option::Some(name);
+        self
+    }
+    ///Sets `port`.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = ::std::option::Option::Some(port);
+        self
+    }
+    ///Builds a new [`Account`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `name`
+    ///
+    ///Returns an error if the value of a field violates its constraints:
+    ///
+    ///- `name`: `non_empty`
+    ///- `name`: `validate = "not_reserved"`
+    ///- `port`: `range(min = 1, max = 65535)`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `port`: `None`
+    pub fn build(&mut self) -> ::std::result::Result<Account, AccountBuilderError> {
+        let name = match &self.name {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(AccountBuilderError::MissingField {
+                        field: "name",
+                    }),
+                );
+            }
+        };
+        {
+            let inner = &name;
+            if inner.is_empty() {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(AccountBuilderError::InvalidField {
+                        field: "name",
+                        constraint: "non_empty",
+                        message: ::std::string::String::from("must not be empty"),
+                    }),
+                );
+            }
+            if let ::std::result::Result::Err(err) = not_reserved(inner) {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(AccountBuilderError::InvalidField {
+                        field: "name",
+                        constraint: "validate = \"not_reserved\"",
+                        message: ::std::string::ToString::to_string(&err),
+                    }),
+                );
+            }
+        }
+        let port = ::std::clone::Clone::clone(&self.port);
+        if let ::std::option::Option::Some(inner) = &port {
+            if *inner < 1 {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(AccountBuilderError::InvalidField {
+                        field: "port",
+                        constraint: "range(min = 1, max = 65535)",
+                        message: ::std::string::String::from("must be at least 1"),
+                    }),
+                );
+            }
+            if *inner > 65535 {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(AccountBuilderError::InvalidField {
+                        field: "port",
+                        constraint: "range(min = 1, max = 65535)",
+                        message: ::std::string::String::from("must be at most 65535"),
+                    }),
+                );
+            }
+        }
+        let value = Account { name: name, port: port };
+        ::std::result::Result::Ok(value)
+    }
+}
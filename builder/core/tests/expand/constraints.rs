@@ -0,0 +1,7 @@
+#[derive(Builder)]
+pub struct Account {
+    #[builder(non_empty, validate = "not_reserved")]
+    name: String,
+    #[builder(range(min = 1, max = 65535))]
+    port: Option<u16>,
+}
@@ -0,0 +1,193 @@
+impl Server {
+    ///Creates a [`ServerBuilder`] with no fields set, to construct a new [`Server`].
+    pub fn builder() -> ServerBuilder {
+        ServerBuilder::new()
+    }
+}
+/**A builder for [`Server`].
+
+Create one with [`Server::builder`], call the setters for the fields you want to set and finish with [`ServerBuilder::build`].*/
+pub struct ServerBuilder {
+    port: String,
+    retries: u8,
+    name: ::std::option::Option<String>,
+}
+///An error returned by [`ServerBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ServerBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ServerBuilderError {}
+///Describes a field set by a [`ServerBuilder`], see [`ServerBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ServerBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl ServerBuilder {
+    ///Describes the fields of [`Server`], in the order they are declared.
+    pub const FIELDS: &'static [ServerBuilderFieldInfo] = &[
+        ServerBuilderFieldInfo {
+            name: "port",
+            ty: "u16",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ServerBuilderFieldInfo {
+            name: "retries",
+            ty: "u32",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ServerBuilderFieldInfo {
+            name: "name",
+            ty: "String",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        ServerBuilder {
+            port: ::std::default::Default::default(),
+            retries: ::std::default::Default::default(),
+            name: ::std::option::Option::None,
+        }
+    }
+    ///Sets `port`.
+    pub fn port(&mut self, port: String) -> &mut Self {
+        self.port = port;
+        self
+    }
+    ///Sets `retries`.
+    pub fn retries(&mut self, retries: u8) -> &mut Self {
+        self.retries = retries;
+        self
+    }
+    ///Sets `name`.
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = ::std::option::Option::Some(name);
+        self
+    }
+    ///Builds a new [`Server`] from the values set on this builder.
+    ///
+    ///These fields are computed from the builder when it is built:
+    ///
+    ///- `port`: `self.port.parse()?`
+    ///- `retries`: converted with `Into` from the value stored in the builder
+    ///- `name`: `self.name.clone().unwrap_or_default()`
+    pub fn build(&mut self) -> ::std::result::Result<Server, ServerBuilderError> {
+        let port = match (|| ::std::result::Result::<
+            _,
+            ::std::boxed::Box<
+                dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+            >,
+        >::Ok(self.port.parse()?))() {
+            ::std::result::Result::Ok(inner) => inner,
+            ::std::result::Result::Err(err) => {
+                return ::std::result::Result::Err(ServerBuilderError::Custom(err));
+            }
+        };
+        let retries = ::std::convert::Into::into(
+            ::std::clone::Clone::clone(&self.retries),
+        );
+        let name = match (|| ::std::result::Result::<
+            _,
+            ::std::boxed::Box<
+                dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+            >,
+        >::Ok(self.name.clone().unwrap_or_default()))() {
+            ::std::result::Result::Ok(inner) => inner,
+            ::std::result::Result::Err(err) => {
+                return ::std::result::Result::Err(ServerBuilderError::Custom(err));
+            }
+        };
+        let value = Server {
+            port: port,
+            retries: retries,
+            name: name,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
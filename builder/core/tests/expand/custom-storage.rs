@@ -0,0 +1,9 @@
+#[derive(Builder)]
+pub struct Server {
+    #[builder(field(ty = "String", build = "self.port.parse()?"))]
+    port: u16,
+    #[builder(field(ty = "u8"))]
+    retries: u32,
+    #[builder(field(build = "self.name.clone().unwrap_or_default()"))]
+    name: String,
+}
@@ -0,0 +1,182 @@
+impl Server {
+    ///Creates a [`ServerBuilder`] with no fields set, to construct a new [`Server`].
+    pub fn builder() -> ServerBuilder {
+        ServerBuilder::new()
+    }
+}
+/**A builder for [`Server`].
+
+Create one with [`Server::builder`], call the setters for the fields you want to set and finish with [`ServerBuilder::build`].*/
+pub struct ServerBuilder {
+    port: ::std::option::Option<u16>,
+    host: ::std::option::Option<String>,
+    retries: ::std::option::Option<u8>,
+}
+///An error returned by [`ServerBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ServerBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ServerBuilderError {}
+///Describes a field set by a [`ServerBuilder`], see [`ServerBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ServerBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl ServerBuilder {
+    ///Describes the fields of [`Server`], in the order they are declared.
+    pub const FIELDS: &'static [ServerBuilderFieldInfo] = &[
+        ServerBuilderFieldInfo {
+            name: "port",
+            ty: "u16",
+            required: false,
+            default: ::std::option::Option::Some("8080"),
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ServerBuilderFieldInfo {
+            name: "host",
+            ty: "String",
+            required: false,
+            default: ::std::option::Option::Some("String::from(\"localhost\")"),
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ServerBuilderFieldInfo {
+            name: "retries",
+            ty: "Option<u8>",
+            required: false,
+            default: ::std::option::Option::Some("Default::default()"),
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        ServerBuilder {
+            port: ::std::option::Option::None,
+            host: ::std::option::Option::None,
+            retries: ::std::option::Option::None,
+        }
+    }
+    ///Sets `port`.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = ::std::option::Option::Some(port);
+        self
+    }
+    ///Sets `host`.
+    pub fn host(&mut self, host: String) -> &mut Self {
+        self.host = ::std::option::Option::Some(host);
+        self
+    }
+    ///Sets `retries`.
+    pub fn retries(&mut self, retries: u8) -> &mut Self {
+        self.retries = ::std::option::Option::Some(retries);
+        self
+    }
+    ///Builds a new [`Server`] from the values set on this builder.
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `port`: `8080`
+    ///- `host`: `String::from("localhost")`
+    ///- `retries`: `Default::default()`
+    pub fn build(&mut self) -> ::std::result::Result<Server, ServerBuilderError> {
+        let port = match &self.port {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => 8080,
+        };
+        let host = match &self.host {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => String::from("localhost"),
+        };
+        let retries = match &self.retries {
+            ::std::option::Option::Some(inner) => {
+                ::std::option::Option::Some(::std::clone::Clone::clone(inner))
+            }
+            ::std::option::Option::None => ::std::default::Default::default(),
+        };
+        let value = Server {
+            port: port,
+            host: host,
+            retries: retries,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
@@ -0,0 +1,9 @@
+#[derive(Builder)]
+pub struct Server {
+    #[builder(default = 8080)]
+    port: u16,
+    #[builder(default = "String::from(\"localhost\")")]
+    host: String,
+    #[builder(default)]
+    retries: Option<u8>,
+}
@@ -0,0 +1,7 @@
+compile_error! {
+    "expected a trait name, e.g. `derive(Debug, Clone)`"
+}
+
+compile_error! {
+    "expected `builder(each = \"...\")`"
+}
@@ -0,0 +1,11 @@
+#[derive(Builder)]
+#[builder(derive(Debug = "yes"))]
+pub struct InvalidDerive {
+    executable: String,
+}
+
+#[derive(Builder)]
+pub struct UnknownFieldOption {
+    #[builder(eac = "arg")]
+    args: Vec<String>,
+}
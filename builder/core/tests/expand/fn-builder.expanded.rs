@@ -0,0 +1,400 @@
+pub fn connect(host: &str, port: u16, timeout: u32) -> String {
+    format!("{}:{} ({}s)", host, port, timeout)
+}
+///Creates a [`ConnectArgsBuilder`] to set the arguments of [`connect`] one by one.
+pub fn connect_builder<'__builder0>() -> ConnectArgsBuilder<'__builder0> {
+    ConnectArgs::builder()
+}
+///The arguments of [`connect`].
+pub struct ConnectArgs<'__builder0> {
+    host: &'__builder0 str,
+    port: u16,
+    timeout: u32,
+}
+impl<'__builder0> ConnectArgs<'__builder0> {
+    ///Creates a [`ConnectArgsBuilder`] with no fields set, to construct a new [`ConnectArgs`].
+    pub fn builder() -> ConnectArgsBuilder<'__builder0> {
+        ConnectArgsBuilder::new()
+    }
+}
+/**A builder for [`ConnectArgs`].
+
+Create one with [`ConnectArgs::builder`], call the setters for the fields you want to set and finish with [`ConnectArgsBuilder::build`].*/
+pub struct ConnectArgsBuilder<'__builder0> {
+    host: ::std::option::Option<&'__builder0 str>,
+    port: ::std::option::Option<u16>,
+    timeout: ::std::option::Option<u32>,
+}
+///An error returned by [`ConnectArgsBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ConnectArgsBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ConnectArgsBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ConnectArgsBuilderError {}
+///Describes a field set by a [`ConnectArgsBuilder`], see [`ConnectArgsBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ConnectArgsBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl<'__builder0> ConnectArgsBuilder<'__builder0> {
+    ///Describes the fields of [`ConnectArgs`], in the order they are declared.
+    pub const FIELDS: &'static [ConnectArgsBuilderFieldInfo] = &[
+        ConnectArgsBuilderFieldInfo {
+            name: "host",
+            ty: "&'__builder0 str",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ConnectArgsBuilderFieldInfo {
+            name: "port",
+            ty: "u16",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ConnectArgsBuilderFieldInfo {
+            name: "timeout",
+            ty: "u32",
+            required: false,
+            default: ::std::option::Option::Some("30"),
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        ConnectArgsBuilder {
+            host: ::std::option::Option::None,
+            port: ::std::option::Option::None,
+            timeout: ::std::option::Option::None,
+        }
+    }
+    ///Sets `host`.
+    pub fn host(&mut self, host: &'__builder0 str) -> &mut Self {
+        self.host = ::std::option::Option::Some(host);
+        self
+    }
+    ///Sets `port`.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = ::std::option::Option::Some(port);
+        self
+    }
+    ///Sets `timeout`.
+    pub fn timeout(&mut self, timeout: u32) -> &mut Self {
+        self.timeout = ::std::option::Option::Some(timeout);
+        self
+    }
+    ///Builds a new [`ConnectArgs`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `host`
+    ///- `port`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `timeout`: `30`
+    pub fn build(
+        &mut self,
+    ) -> ::std::result::Result<ConnectArgs<'__builder0>, ConnectArgsBuilderError> {
+        let host = match &self.host {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConnectArgsBuilderError::MissingField {
+                        field: "host",
+                    }),
+                );
+            }
+        };
+        let port = match &self.port {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConnectArgsBuilderError::MissingField {
+                        field: "port",
+                    }),
+                );
+            }
+        };
+        let timeout = match &self.timeout {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => 30,
+        };
+        let value = ConnectArgs {
+            host: host,
+            port: port,
+            timeout: timeout,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
+impl<'__builder0> ConnectArgsBuilder<'__builder0> {
+    /**Calls [`connect`] with the arguments set on this builder.
+
+# Errors
+
+Returns an error if building the arguments fails, see [`ConnectArgsBuilder::build`].*/
+    pub fn call(&mut self) -> ::std::result::Result<String, ConnectArgsBuilderError> {
+        let ConnectArgs { host: host, port: port, timeout: timeout } = self.build()?;
+        ::std::result::Result::Ok(connect(host, port, timeout))
+    }
+}
+
+impl Client {
+    fn request(&self, path: &str) -> Response {
+        self.get(path)
+    }
+    ///Creates a [`ClientRequestArgsBuilder`] to set the arguments of [`Client::request`] one by one.
+    fn request_builder<'__builder0>() -> ClientRequestArgsBuilder<'__builder0> {
+        ClientRequestArgs::builder()
+    }
+}
+///The arguments of [`Client::request`].
+struct ClientRequestArgs<'__builder0> {
+    path: &'__builder0 str,
+}
+impl<'__builder0> ClientRequestArgs<'__builder0> {
+    ///Creates a [`ClientRequestArgsBuilder`] with no fields set, to construct a new [`ClientRequestArgs`].
+    pub fn builder() -> ClientRequestArgsBuilder<'__builder0> {
+        ClientRequestArgsBuilder::new()
+    }
+}
+/**A builder for [`ClientRequestArgs`].
+
+Create one with [`ClientRequestArgs::builder`], call the setters for the fields you want to set and finish with [`ClientRequestArgsBuilder::build`].*/
+pub struct ClientRequestArgsBuilder<'__builder0> {
+    path: ::std::option::Option<&'__builder0 str>,
+}
+///An error returned by [`ClientRequestArgsBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ClientRequestArgsBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ClientRequestArgsBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ClientRequestArgsBuilderError {}
+///Describes a field set by a [`ClientRequestArgsBuilder`], see [`ClientRequestArgsBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ClientRequestArgsBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl<'__builder0> ClientRequestArgsBuilder<'__builder0> {
+    ///Describes the fields of [`ClientRequestArgs`], in the order they are declared.
+    pub const FIELDS: &'static [ClientRequestArgsBuilderFieldInfo] = &[
+        ClientRequestArgsBuilderFieldInfo {
+            name: "path",
+            ty: "&'__builder0 str",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        ClientRequestArgsBuilder {
+            path: ::std::option::Option::None,
+        }
+    }
+    ///Sets `path`.
+    fn path(&mut self, path: &'__builder0 str) -> &mut Self {
+        self.path = ::std::option::Option::Some(path);
+        self
+    }
+    ///Builds a new [`ClientRequestArgs`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `path`
+    pub fn build(
+        &mut self,
+    ) -> ::std::result::Result<
+        ClientRequestArgs<'__builder0>,
+        ClientRequestArgsBuilderError,
+    > {
+        let path = match &self.path {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ClientRequestArgsBuilderError::MissingField {
+                        field: "path",
+                    }),
+                );
+            }
+        };
+        let value = ClientRequestArgs { path: path };
+        ::std::result::Result::Ok(value)
+    }
+}
+impl<'__builder0> ClientRequestArgsBuilder<'__builder0> {
+    /**Calls [`Client::request`] with the arguments set on this builder.
+
+# Errors
+
+Returns an error if building the arguments fails, see [`ClientRequestArgsBuilder::build`].*/
+    fn call(
+        &mut self,
+        receiver: &Client,
+    ) -> ::std::result::Result<Response, ClientRequestArgsBuilderError> {
+        let ClientRequestArgs { path: path } = self.build()?;
+        ::std::result::Result::Ok(<Client>::request(receiver, path))
+    }
+}
@@ -0,0 +1,12 @@
+#[builder]
+pub fn connect(host: &str, port: u16, #[builder(default = 30)] timeout: u32) -> String {
+    format!("{}:{} ({}s)", host, port, timeout)
+}
+
+#[builder]
+impl Client {
+    #[builder]
+    fn request(&self, path: &str) -> Response {
+        self.get(path)
+    }
+}
@@ -0,0 +1,186 @@
+impl Command {
+    ///Creates a [`CommandBuilder`] with no fields set, to construct a new [`Command`].
+    pub fn builder() -> CommandBuilder {
+        CommandBuilder::new()
+    }
+}
+/**A builder for [`Command`].
+
+Create one with [`Command::builder`], call the setters for the fields you want to set and finish with [`CommandBuilder::build`].*/
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CommandBuilder {
+    executable: ::std::option::Option<String>,
+    #[doc(hidden)]
+    args: ::std::option::Option<Vec<String>>,
+}
+///An error returned by [`CommandBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum CommandBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for CommandBuilderError {}
+///Describes a field set by a [`CommandBuilder`], see [`CommandBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct CommandBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl CommandBuilder {
+    ///Describes the fields of [`Command`], in the order they are declared.
+    pub const FIELDS: &'static [CommandBuilderFieldInfo] = &[
+        CommandBuilderFieldInfo {
+            name: "executable",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "The program to run.",
+        },
+        CommandBuilderFieldInfo {
+            name: "args",
+            ty: "Vec<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::Some("arg"),
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        CommandBuilder {
+            executable: ::std::option::Option::None,
+            args: ::std::option::Option::Some(::std::vec::Vec::new()),
+        }
+    }
+    /// The program to run.
+    pub fn executable(&mut self, executable: String) -> &mut Self {
+        self.executable = ::std::option::Option::Some(executable);
+        self
+    }
+    ///Sets `args`.
+    #[inline]
+    pub fn args(&mut self, args: Vec<String>) -> &mut Self {
+        self.args = ::std::option::Option::Some(args);
+        self
+    }
+    ///Appends a single element to `args`.
+    #[inline]
+    pub fn arg(&mut self, arg: String) -> &mut Self {
+        self.args.get_or_insert_with(|| ::std::vec::Vec::new()).push(arg);
+        self
+    }
+    ///Builds a new [`Command`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `executable`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `args`: an empty `Vec`
+    pub fn build(&mut self) -> ::std::result::Result<Command, CommandBuilderError> {
+        let executable = match &self.executable {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(CommandBuilderError::MissingField {
+                        field: "executable",
+                    }),
+                );
+            }
+        };
+        let args = match &self.args {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(CommandBuilderError::MissingField {
+                        field: "args",
+                    }),
+                );
+            }
+        };
+        let value = Command {
+            executable: executable,
+            args: args,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Builder)]
+#[builder(derive(Debug, Clone), struct_attr(must_use))]
+pub struct Command {
+    /// The program to run.
+    executable: String,
+    #[builder(each = "arg", field_attr(doc(hidden)), setter_attr(inline))]
+    args: Vec<String>,
+}
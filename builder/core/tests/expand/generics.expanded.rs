@@ -0,0 +1,219 @@
+impl<'a, T: Clone, const N: usize> Request<'a, T, N>
+where
+    T: Debug,
+{
+    ///Creates a [`RequestBuilder`] with no fields set, to construct a new [`Request`].
+    pub fn builder() -> RequestBuilder<'a, T, N> {
+        RequestBuilder::new()
+    }
+}
+/**A builder for [`Request`].
+
+Create one with [`Request::builder`], call the setters for the fields you want to set and finish with [`RequestBuilder::build`].*/
+pub struct RequestBuilder<'a, T: Clone, const N: usize>
+where
+    T: Debug,
+{
+    path: ::std::option::Option<&'a str>,
+    body: ::std::option::Option<T>,
+    headers: ::std::option::Option<Vec<[u8; N]>>,
+}
+///An error returned by [`RequestBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum RequestBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for RequestBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for RequestBuilderError {}
+///Describes a field set by a [`RequestBuilder`], see [`RequestBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct RequestBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl<'a, T: Clone, const N: usize> RequestBuilder<'a, T, N>
+where
+    T: Debug,
+{
+    ///Describes the fields of [`Request`], in the order they are declared.
+    pub const FIELDS: &'static [RequestBuilderFieldInfo] = &[
+        RequestBuilderFieldInfo {
+            name: "path",
+            ty: "&'a str",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        RequestBuilderFieldInfo {
+            name: "body",
+            ty: "T",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        RequestBuilderFieldInfo {
+            name: "headers",
+            ty: "Vec<[u8 ; N]>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::Some("header"),
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        RequestBuilder {
+            path: ::std::option::Option::None,
+            body: ::std::option::Option::None,
+            headers: ::std::option::Option::Some(::std::vec::Vec::new()),
+        }
+    }
+    ///Sets `path`.
+    pub fn path(&mut self, path: &'a str) -> &mut Self {
+        self.path = ::std::option::Option::Some(path);
+        self
+    }
+    ///Sets `body`.
+    pub fn body(&mut self, body: T) -> &mut Self {
+        self.body = ::std::option::Option::Some(body);
+        self
+    }
+    ///Sets `headers`.
+    pub fn headers(&mut self, headers: Vec<[u8; N]>) -> &mut Self {
+        self.headers = ::std::option::Option::Some(headers);
+        self
+    }
+    ///Appends a single element to `headers`.
+    pub fn header(&mut self, header: [u8; N]) -> &mut Self {
+        self.headers.get_or_insert_with(|| ::std::vec::Vec::new()).push(header);
+        self
+    }
+    ///Builds a new [`Request`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `path`
+    ///- `body`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `headers`: an empty `Vec`
+    pub fn build(
+        &mut self,
+    ) -> ::std::result::Result<Request<'a, T, N>, RequestBuilderError> {
+        let path = match &self.path {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(RequestBuilderError::MissingField {
+                        field: "path",
+                    }),
+                );
+            }
+        };
+        let body = match &self.body {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(RequestBuilderError::MissingField {
+                        field: "body",
+                    }),
+                );
+            }
+        };
+        let headers = match &self.headers {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(RequestBuilderError::MissingField {
+                        field: "headers",
+                    }),
+                );
+            }
+        };
+        let value = Request {
+            path: path,
+            body: body,
+            headers: headers,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
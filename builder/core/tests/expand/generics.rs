@@ -0,0 +1,10 @@
+#[derive(Builder)]
+pub struct Request<'a, T: Clone, const N: usize>
+where
+    T: Debug,
+{
+    path: &'a str,
+    body: T,
+    #[builder(each = "header")]
+    headers: Vec<[u8; N]>,
+}
@@ -0,0 +1,255 @@
+impl User {
+    ///Creates a [`UserBuilder`] with no fields set, to construct a new [`User`].
+    pub fn builder() -> UserBuilder {
+        UserBuilder::new()
+    }
+}
+/**A builder for [`User`].
+
+Create one with [`User::builder`], call the setters for the fields you want to set and finish with [`UserBuilder::build`].*/
+pub struct UserBuilder {
+    name: ::std::option::Option<String>,
+    roles: ::std::option::Option<Vec<String>>,
+    nickname: ::std::option::Option<String>,
+}
+///An error returned by [`UserBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum UserBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for UserBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for UserBuilderError {}
+///Describes a field set by a [`UserBuilder`], see [`UserBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct UserBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl UserBuilder {
+    ///Describes the fields of [`User`], in the order they are declared.
+    pub const FIELDS: &'static [UserBuilderFieldInfo] = &[
+        UserBuilderFieldInfo {
+            name: "name",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "The user's display name.",
+        },
+        UserBuilderFieldInfo {
+            name: "roles",
+            ty: "Vec<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::Some("role"),
+            docs: "",
+        },
+        UserBuilderFieldInfo {
+            name: "nickname",
+            ty: "Option<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        UserBuilder {
+            name: ::std::option::Option::None,
+            roles: ::std::option::Option::Some(::std::vec::Vec::new()),
+            nickname: ::std::option::Option::None,
+        }
+    }
+    /// The user's display name.
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = ::std::option::Option::Some(name);
+        self
+    }
+    ///Sets `roles`.
+    pub fn roles(&mut self, roles: Vec<String>) -> &mut Self {
+        self.roles = ::std::option::Option::Some(roles);
+        self
+    }
+    ///Sets `nickname`.
+    pub fn nickname(&mut self, nickname: String) -> &mut Self {
+        self.nickname = ::std::option::Option::Some(nickname);
+        self
+    }
+    ///Appends a single element to `roles`.
+    pub fn role(&mut self, role: String) -> &mut Self {
+        self.roles.get_or_insert_with(|| ::std::vec::Vec::new()).push(role);
+        self
+    }
+    ///Builds a new [`User`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `name`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `roles`: an empty `Vec`
+    ///- `nickname`: `None`
+    pub fn build(&mut self) -> ::std::result::Result<User, UserBuilderError> {
+        let name = match &self.name {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(UserBuilderError::MissingField {
+                        field: "name",
+                    }),
+                );
+            }
+        };
+        let roles = match &self.roles {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(UserBuilderError::MissingField {
+                        field: "roles",
+                    }),
+                );
+            }
+        };
+        let nickname = ::std::clone::Clone::clone(&self.nickname);
+        let value = User {
+            name: name,
+            roles: roles,
+            nickname: nickname,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
+/**A partial update of a [`User`], holding a new value for each field that is `Some`.
+
+Apply it with [`User::apply`], and create one with [`UserBuilder::build_partial`] or by setting its fields directly.*/
+pub struct UserPatch {
+    /// The user's display name.
+    pub name: ::std::option::Option<String>,
+    pub roles: ::std::option::Option<Vec<String>>,
+    pub nickname: ::std::option::Option<Option<String>>,
+}
+impl ::std::default::Default for UserPatch {
+    fn default() -> Self {
+        UserPatch {
+            name: ::std::option::Option::None,
+            roles: ::std::option::Option::None,
+            nickname: ::std::option::Option::None,
+        }
+    }
+}
+impl User {
+    ///Overwrites each field of this `User` whose value in `patch` is `Some`.
+    pub fn apply(&mut self, patch: UserPatch) {
+        if let ::std::option::Option::Some(name) = patch.name {
+            self.name = name;
+        }
+        if let ::std::option::Option::Some(roles) = patch.roles {
+            self.roles = roles;
+        }
+        if let ::std::option::Option::Some(nickname) = patch.nickname {
+            self.nickname = nickname;
+        }
+    }
+}
+impl UserBuilder {
+    /**Collects the fields set on this builder into a [`UserPatch`], without checking that required fields are set or applying defaults.
+
+Fields filled with `each` count as set once they hold at least one element.*/
+    pub fn build_partial(&self) -> UserPatch {
+        UserPatch {
+            name: ::std::clone::Clone::clone(&self.name),
+            roles: match &self.roles {
+                ::std::option::Option::Some(inner) if !inner.is_empty() => {
+                    ::std::option::Option::Some(::std::clone::Clone::clone(inner))
+                }
+                _ => ::std::option::Option::None,
+            },
+            nickname: match &self.nickname {
+                ::std::option::Option::Some(inner) => {
+                    ::std::option::Option::Some(
+                        ::std::option::Option::Some(::std::clone::Clone::clone(inner)),
+                    )
+                }
+                ::std::option::Option::None => ::std::option::Option::None,
+            },
+        }
+    }
+}
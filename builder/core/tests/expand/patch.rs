@@ -0,0 +1,9 @@
+#[derive(Builder)]
+#[builder(patch)]
+pub struct User {
+    /// The user's display name.
+    name: String,
+    #[builder(each = "role")]
+    roles: Vec<String>,
+    nickname: Option<String>,
+}
@@ -0,0 +1,199 @@
+impl Pattern {
+    ///Creates a [`PatternBuilder`] with no fields set, to construct a new [`Pattern`].
+    pub fn builder() -> PatternBuilder {
+        PatternBuilder::new()
+    }
+}
+/**A builder for [`Pattern`].
+
+Create one with [`Pattern::builder`], call the setters for the fields you want to set and finish with [`PatternBuilder::build`].*/
+pub struct PatternBuilder {
+    r#type: ::std::option::Option<String>,
+    r#name: ::std::option::Option<String>,
+    r#matches: ::std::option::Option<Vec<String>>,
+}
+///An error returned by [`PatternBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum PatternBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for PatternBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for PatternBuilderError {}
+///Describes a field set by a [`PatternBuilder`], see [`PatternBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct PatternBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl PatternBuilder {
+    ///Describes the fields of [`Pattern`], in the order they are declared.
+    pub const FIELDS: &'static [PatternBuilderFieldInfo] = &[
+        PatternBuilderFieldInfo {
+            name: "type",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        PatternBuilderFieldInfo {
+            name: "name",
+            ty: "Option<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        PatternBuilderFieldInfo {
+            name: "matches",
+            ty: "Vec<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::Some("match"),
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        PatternBuilder {
+            r#type: ::std::option::Option::None,
+            r#name: ::std::option::Option::None,
+            r#matches: ::std::option::Option::Some(::std::vec::Vec::new()),
+        }
+    }
+    ///Sets `type`.
+    pub fn r#type(&mut self, r#type: String) -> &mut Self {
+        self.r#type = ::std::option::Option::Some(r#type);
+        self
+    }
+    ///Sets `name`.
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.r#name = ::std::option::Option::Some(name);
+        self
+    }
+    ///Sets `matches`.
+    pub fn matches(&mut self, matches: Vec<String>) -> &mut Self {
+        self.r#matches = ::std::option::Option::Some(matches);
+        self
+    }
+    ///Appends a single element to `matches`.
+    pub fn r#match(&mut self, r#match: String) -> &mut Self {
+        self.r#matches.get_or_insert_with(|| ::std::vec::Vec::new()).push(r#match);
+        self
+    }
+    ///Builds a new [`Pattern`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `type`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `name`: `None`
+    ///- `matches`: an empty `Vec`
+    pub fn build(&mut self) -> ::std::result::Result<Pattern, PatternBuilderError> {
+        let r#type = match &self.r#type {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(PatternBuilderError::MissingField {
+                        field: "type",
+                    }),
+                );
+            }
+        };
+        let r#name = ::std::clone::Clone::clone(&self.r#name);
+        let r#matches = match &self.r#matches {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(PatternBuilderError::MissingField {
+                        field: "matches",
+                    }),
+                );
+            }
+        };
+        let value = Pattern {
+            r#type: r#type,
+            r#name: r#name,
+            r#matches: r#matches,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Builder)]
+pub struct Pattern {
+    r#type: String,
+    r#name: Option<String>,
+    #[builder(each = "match")]
+    r#matches: Vec<String>,
+}
@@ -0,0 +1,228 @@
+impl Connection {
+    ///Creates a [`ConnectionBuilder`] with no fields set, to construct a new [`Connection`].
+    pub fn builder() -> ConnectionBuilder {
+        ConnectionBuilder::new()
+    }
+}
+/**A builder for [`Connection`].
+
+Create one with [`Connection::builder`], call the setters for the fields you want to set and finish with [`ConnectionBuilder::build`].*/
+pub struct ConnectionBuilder {
+    cert_path: ::std::option::Option<String>,
+    cert_pem: ::std::option::Option<String>,
+    username: ::std::option::Option<String>,
+    password: ::std::option::Option<String>,
+}
+///An error returned by [`ConnectionBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ConnectionBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ConnectionBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ConnectionBuilderError {}
+///Describes a field set by a [`ConnectionBuilder`], see [`ConnectionBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ConnectionBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl ConnectionBuilder {
+    ///Describes the fields of [`Connection`], in the order they are declared.
+    pub const FIELDS: &'static [ConnectionBuilderFieldInfo] = &[
+        ConnectionBuilderFieldInfo {
+            name: "cert_path",
+            ty: "Option<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ConnectionBuilderFieldInfo {
+            name: "cert_pem",
+            ty: "Option<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ConnectionBuilderFieldInfo {
+            name: "username",
+            ty: "Option<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ConnectionBuilderFieldInfo {
+            name: "password",
+            ty: "Option<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        ConnectionBuilder {
+            cert_path: ::std::option::Option::None,
+            cert_pem: ::std::option::Option::None,
+            username: ::std::option::Option::None,
+            password: ::std::option::Option::None,
+        }
+    }
+    ///Sets `cert_path`.
+    pub fn cert_path(&mut self, cert_path: String) -> &mut Self {
+        self.cert_path = ::std::option::Option::Some(cert_path);
+        self
+    }
+    ///Sets `cert_pem`.
+    pub fn cert_pem(&mut self, cert_pem: String) -> &mut Self {
+        self.cert_pem = ::std::option::Option::Some(cert_pem);
+        self
+    }
+    ///Sets `username`.
+    pub fn username(&mut self, username: String) -> &mut Self {
+        self.username = ::std::option::Option::Some(username);
+        self
+    }
+    ///Sets `password`.
+    pub fn password(&mut self, password: String) -> &mut Self {
+        self.password = ::std::option::Option::Some(password);
+        self
+    }
+    ///Builds a new [`Connection`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if the fields that have been set violate any of:
+    ///
+    ///- exactly one of `cert_path`, `cert_pem` must be set
+    ///- if `username` is set, `password` must be set too
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `cert_path`: `None`
+    ///- `cert_pem`: `None`
+    ///- `username`: `None`
+    ///- `password`: `None`
+    pub fn build(
+        &mut self,
+    ) -> ::std::result::Result<Connection, ConnectionBuilderError> {
+        match ::std::primitive::usize::from(
+            ::std::option::Option::is_some(&self.cert_path),
+        ) + ::std::primitive::usize::from(::std::option::Option::is_some(&self.cert_pem))
+        {
+            0 => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConnectionBuilderError::NoneSet {
+                        fields: &["cert_path", "cert_pem"],
+                    }),
+                );
+            }
+            1 => {}
+            _ => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConnectionBuilderError::ConflictingFields {
+                        fields: &["cert_path", "cert_pem"],
+                    }),
+                );
+            }
+        }
+        if ::std::option::Option::is_some(&self.username) {
+            if !::std::option::Option::is_some(&self.password) {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ConnectionBuilderError::MissingDependency {
+                        field: "username",
+                        requires: "password",
+                    }),
+                );
+            }
+        }
+        let cert_path = ::std::clone::Clone::clone(&self.cert_path);
+        let cert_pem = ::std::clone::Clone::clone(&self.cert_pem);
+        let username = ::std::clone::Clone::clone(&self.username);
+        let password = ::std::clone::Clone::clone(&self.password);
+        let value = Connection {
+            cert_path: cert_path,
+            cert_pem: cert_pem,
+            username: username,
+            password: password,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
@@ -0,0 +1,8 @@
+#[derive(Builder)]
+#[builder(one_of(cert_path, cert_pem), requires(username, password))]
+pub struct Connection {
+    cert_path: Option<String>,
+    cert_pem: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
+}
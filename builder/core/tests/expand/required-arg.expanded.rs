@@ -0,0 +1,166 @@
+impl User {
+    ///Creates a [`UserBuilder`] with `id` and `name` set to the given values, to construct a new [`User`].
+    pub fn builder(id: u64, name: String) -> UserBuilder {
+        UserBuilder::new(id, name)
+    }
+}
+/**A builder for [`User`].
+
+Create one with [`User::builder`], call the setters for the fields you want to set and finish with [`UserBuilder::build`].*/
+pub struct UserBuilder {
+    id: u64,
+    name: String,
+    email: ::std::option::Option<String>,
+}
+///An error returned by [`UserBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum UserBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for UserBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for UserBuilderError {}
+///Describes a field set by a [`UserBuilder`], see [`UserBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct UserBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl UserBuilder {
+    ///Describes the fields of [`User`], in the order they are declared.
+    pub const FIELDS: &'static [UserBuilderFieldInfo] = &[
+        UserBuilderFieldInfo {
+            name: "id",
+            ty: "u64",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        UserBuilderFieldInfo {
+            name: "name",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        UserBuilderFieldInfo {
+            name: "email",
+            ty: "Option<String>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with `id` and `name` set to the given values.
+    pub fn new(id: u64, name: String) -> Self {
+        UserBuilder {
+            id: id,
+            name: name,
+            email: ::std::option::Option::None,
+        }
+    }
+    ///Sets `email`.
+    pub fn email(&mut self, email: String) -> &mut Self {
+        self.email = ::std::option::Option::Some(email);
+        self
+    }
+    ///Builds a new [`User`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `id`
+    ///- `name`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `email`: `None`
+    pub fn build(&mut self) -> ::std::result::Result<User, UserBuilderError> {
+        let id = ::std::clone::Clone::clone(&self.id);
+        let name = ::std::clone::Clone::clone(&self.name);
+        let email = ::std::clone::Clone::clone(&self.email);
+        let value = User {
+            id: id,
+            name: name,
+            email: email,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
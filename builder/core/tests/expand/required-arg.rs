@@ -0,0 +1,8 @@
+#[derive(Builder)]
+pub struct User {
+    #[builder(required_arg)]
+    id: u64,
+    #[builder(required_arg)]
+    name: String,
+    email: Option<String>,
+}
@@ -0,0 +1,323 @@
+impl<T> Credentials<T> {
+    ///Creates a [`CredentialsBuilder`] with no fields set, to construct a new [`Credentials`].
+    pub fn builder() -> CredentialsBuilder<T> {
+        CredentialsBuilder::new()
+    }
+}
+/**A builder for [`Credentials`].
+
+Create one with [`Credentials::builder`], call the setters for the fields you want to set and finish with [`CredentialsBuilder::build`].*/
+pub struct CredentialsBuilder<T> {
+    username: ::std::option::Option<T>,
+    password: ::std::option::Option<String>,
+}
+impl<T> ::std::fmt::Debug for CredentialsBuilder<T>
+where
+    T: ::std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let mut debug = f.debug_struct("CredentialsBuilder");
+        debug.field("username", &self.username);
+        debug.field("password", &"<redacted>");
+        debug.finish()
+    }
+}
+///An error returned by [`CredentialsBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum CredentialsBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for CredentialsBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for CredentialsBuilderError {}
+///Describes a field set by a [`CredentialsBuilder`], see [`CredentialsBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct CredentialsBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl<T> CredentialsBuilder<T> {
+    ///Describes the fields of [`Credentials`], in the order they are declared.
+    pub const FIELDS: &'static [CredentialsBuilderFieldInfo] = &[
+        CredentialsBuilderFieldInfo {
+            name: "username",
+            ty: "T",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        CredentialsBuilderFieldInfo {
+            name: "password",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        CredentialsBuilder {
+            username: ::std::option::Option::None,
+            password: ::std::option::Option::None,
+        }
+    }
+    ///Sets `username`.
+    pub fn username(&mut self, username: T) -> &mut Self {
+        self.username = ::std::option::Option::Some(username);
+        self
+    }
+    ///Sets `password`.
+    pub fn password(&mut self, password: String) -> &mut Self {
+        self.password = ::std::option::Option::Some(password);
+        self
+    }
+    ///Builds a new [`Credentials`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `username`
+    ///- `password`
+    pub fn build(
+        &mut self,
+    ) -> ::std::result::Result<Credentials<T>, CredentialsBuilderError> {
+        let username = match &self.username {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(CredentialsBuilderError::MissingField {
+                        field: "username",
+                    }),
+                );
+            }
+        };
+        let password = match &self.password {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(CredentialsBuilderError::MissingField {
+                        field: "password",
+                    }),
+                );
+            }
+        };
+        let value = Credentials {
+            username: username,
+            password: password,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
+
+impl Token {
+    ///Creates a [`TokenBuilder`] with no fields set, to construct a new [`Token`].
+    pub fn builder() -> TokenBuilder {
+        TokenBuilder::new()
+    }
+}
+/**A builder for [`Token`].
+
+Create one with [`Token::builder`], call the setters for the fields you want to set and finish with [`TokenBuilder::build`].*/
+pub struct TokenBuilder {
+    password: ::std::option::Option<String>,
+}
+///An error returned by [`TokenBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum TokenBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for TokenBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for TokenBuilderError {}
+///Describes a field set by a [`TokenBuilder`], see [`TokenBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct TokenBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl TokenBuilder {
+    ///Describes the fields of [`Token`], in the order they are declared.
+    pub const FIELDS: &'static [TokenBuilderFieldInfo] = &[
+        TokenBuilderFieldInfo {
+            name: "password",
+            ty: "String",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        TokenBuilder {
+            password: ::std::option::Option::None,
+        }
+    }
+    ///Sets `password`.
+    pub fn password(&mut self, password: String) -> &mut Self {
+        self.password = ::std::option::Option::Some(password);
+        self
+    }
+    ///Builds a new [`Token`] from the values set on this builder.
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `password`: a default
+    pub fn build(&mut self) -> ::std::result::Result<Token, TokenBuilderError> {
+        let password = match &self.password {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => "hunter2".to_owned(),
+        };
+        let value = Token { password: password };
+        ::std::result::Result::Ok(value)
+    }
+}
@@ -0,0 +1,13 @@
+#[derive(Builder)]
+#[builder(derive(Debug))]
+pub struct Credentials<T> {
+    username: T,
+    #[builder(secret)]
+    password: String,
+}
+
+#[derive(Builder)]
+pub struct Token {
+    #[builder(secret, default = "\"hunter2\".to_owned()")]
+    password: String,
+}
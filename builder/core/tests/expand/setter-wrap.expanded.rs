@@ -0,0 +1,202 @@
+impl<'a> Server<'a> {
+    ///Creates a [`ServerBuilder`] with no fields set, to construct a new [`Server`].
+    pub fn builder() -> ServerBuilder<'a> {
+        ServerBuilder::new()
+    }
+}
+/**A builder for [`Server`].
+
+Create one with [`Server::builder`], call the setters for the fields you want to set and finish with [`ServerBuilder::build`].*/
+pub struct ServerBuilder<'a> {
+    settings: ::std::option::Option<Arc<Settings>>,
+    name: ::std::option::Option<Cow<'a, str>>,
+    root: ::std::option::Option<PathBuf>,
+}
+///An error returned by [`ServerBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ServerBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ServerBuilderError {}
+///Describes a field set by a [`ServerBuilder`], see [`ServerBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ServerBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl<'a> ServerBuilder<'a> {
+    ///Describes the fields of [`Server`], in the order they are declared.
+    pub const FIELDS: &'static [ServerBuilderFieldInfo] = &[
+        ServerBuilderFieldInfo {
+            name: "settings",
+            ty: "Arc<Settings>",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ServerBuilderFieldInfo {
+            name: "name",
+            ty: "Cow<'a, str>",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ServerBuilderFieldInfo {
+            name: "root",
+            ty: "Option<PathBuf>",
+            required: false,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with no fields set.
+    pub fn new() -> Self {
+        ServerBuilder {
+            settings: ::std::option::Option::None,
+            name: ::std::option::Option::None,
+            root: ::std::option::Option::None,
+        }
+    }
+    ///Sets `settings`.
+    pub fn settings(&mut self, settings: Settings) -> &mut Self {
+        self.settings = ::std::option::Option::Some(<Arc<Settings>>::new(settings));
+        self
+    }
+    ///Sets `name`.
+    pub fn name(&mut self, name: impl ::std::convert::Into<Cow<'a, str>>) -> &mut Self {
+        self.name = ::std::option::Option::Some(::std::convert::Into::into(name));
+        self
+    }
+    ///Sets `root`.
+    pub fn root(
+        &mut self,
+        root: impl ::std::convert::AsRef<::std::path::Path>,
+    ) -> &mut Self {
+        self
+            .root = ::std::option::Option::Some(
+            ::std::path::Path::to_path_buf(
+                ::std::convert::AsRef::<::std::path::Path>::as_ref(&root),
+            ),
+        );
+        self
+    }
+    ///Builds a new [`Server`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `settings`
+    ///- `name`
+    ///
+    ///Fields that are not set take these defaults:
+    ///
+    ///- `root`: `None`
+    pub fn build(&mut self) -> ::std::result::Result<Server<'a>, ServerBuilderError> {
+        let settings = match &self.settings {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ServerBuilderError::MissingField {
+                        field: "settings",
+                    }),
+                );
+            }
+        };
+        let name = match &self.name {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ServerBuilderError::MissingField {
+                        field: "name",
+                    }),
+                );
+            }
+        };
+        let root = ::std::clone::Clone::clone(&self.root);
+        let value = Server {
+            settings: settings,
+            name: name,
+            root: root,
+        };
+        ::std::result::Result::Ok(value)
+    }
+}
@@ -0,0 +1,9 @@
+#[derive(Builder)]
+pub struct Server<'a> {
+    #[builder(setter(wrap))]
+    settings: Arc<Settings>,
+    #[builder(setter(wrap))]
+    name: Cow<'a, str>,
+    #[builder(setter(wrap))]
+    root: Option<PathBuf>,
+}
@@ -0,0 +1,249 @@
+impl Server {
+    ///Creates a [`ServerBuilder`] with `name` set to the given value, to construct a new [`Server`].
+    pub fn builder(name: String) -> ServerBuilder {
+        ServerBuilder::new(name)
+    }
+}
+/**A builder for [`Server`].
+
+Create one with [`Server::builder`], call the setters for the fields you want to set and finish with [`ServerBuilder::build`].*/
+pub struct ServerBuilder {
+    name: String,
+    port: ::std::option::Option<u16>,
+    __builder_sources: [::std::option::Option<ServerBuilderSource>; 2],
+    __builder_source_label: ::std::option::Option<::std::string::String>,
+}
+///An error returned by [`ServerBuilder::build`].
+#[derive(::std::fmt::Debug)]
+pub enum ServerBuilderError {
+    /// A required field has not been set.
+    MissingField {
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The value of a field violates one of its constraints.
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The violated constraint, as written in the `#[builder]` attribute.
+        constraint: &'static str,
+        /// Why the value was rejected.
+        message: ::std::string::String,
+        /// Where the rejected value came from.
+        source: ::std::option::Option<ServerBuilderSource>,
+    },
+    /// None of the fields of a `one_of` constraint has been set.
+    NoneSet {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// More than one of the fields of a `one_of` or `conflicts`
+    /// constraint has been set.
+    ConflictingFields {
+        /// The fields of the constraint.
+        fields: &'static [&'static str],
+    },
+    /// A field has been set without a field it `requires`.
+    MissingDependency {
+        /// The field that has been set.
+        field: &'static str,
+        /// The field it requires, which has not been set.
+        requires: &'static str,
+    },
+    /// An error returned by the `build_fn(validate)` function, or by
+    /// a `?` in a `field(build)` expression.
+    Custom(
+        ::std::boxed::Box<
+            dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync,
+        >,
+    ),
+}
+impl ::std::fmt::Display for ServerBuilderError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingField { field } => ::std::write!(f, "{} must be set", field),
+            Self::InvalidField {
+                field,
+                message,
+                source: ::std::option::Option::Some(source),
+                ..
+            } => ::std::write!(f, "invalid {} (from {}): {}", field, source, message),
+            Self::InvalidField { field, message, .. } => {
+                ::std::write!(f, "invalid {}: {}", field, message)
+            }
+            Self::NoneSet { fields } => {
+                ::std::write!(f, "one of {} must be set", fields.join(", "))
+            }
+            Self::ConflictingFields { fields } => {
+                ::std::write!(f, "only one of {} can be set", fields.join(", "))
+            }
+            Self::MissingDependency { field, requires } => {
+                ::std::write!(f, "{} requires {} to be set", field, requires)
+            }
+            Self::Custom(err) => ::std::fmt::Display::fmt(err, f),
+        }
+    }
+}
+impl ::std::error::Error for ServerBuilderError {}
+///Where the value of a field of a [`ServerBuilder`] came from, see [`ServerBuilder::source_of`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+    ::std::hash::Hash,
+    ::std::cmp::PartialOrd,
+    ::std::cmp::Ord,
+)]
+pub enum ServerBuilderSource {
+    ///Given to [`Server::builder`] as a `required_arg`.
+    Argument,
+    ///Set by the method `method`, while the label set with [`ServerBuilder::source_label`] was `label`.
+    Setter {
+        /// The name of the method that set the value: a setter, an
+        /// element setter, an alias or `parse_args`.
+        method: &'static str,
+        /// The label describing where the setter's value came from.
+        label: ::std::option::Option<::std::string::String>,
+    },
+}
+impl ::std::fmt::Display for ServerBuilderSource {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::Argument => ::std::fmt::Formatter::write_str(f, "builder argument"),
+            Self::Setter { label: ::std::option::Option::Some(label), .. } => {
+                ::std::fmt::Formatter::write_str(f, label)
+            }
+            Self::Setter { method, label: ::std::option::Option::None } => {
+                ::std::write!(f, "{}()", method)
+            }
+        }
+    }
+}
+///Describes a field set by a [`ServerBuilder`], see [`ServerBuilder::FIELDS`].
+#[derive(
+    ::std::fmt::Debug,
+    ::std::clone::Clone,
+    ::std::marker::Copy,
+    ::std::cmp::PartialEq,
+    ::std::cmp::Eq,
+)]
+pub struct ServerBuilderFieldInfo {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The type of the field, as written in the struct.
+    pub ty: &'static str,
+    /// Whether the field must be given for the build to succeed.
+    pub required: bool,
+    /// The value taken by the field when it is not set, as written in
+    /// its `default` option. It is left out for a `secret` field.
+    pub default: ::std::option::Option<&'static str>,
+    /// The name of the field's element setter, from its `each` option.
+    pub each: ::std::option::Option<&'static str>,
+    /// The documentation of the field.
+    pub docs: &'static str,
+}
+impl ServerBuilder {
+    ///Describes the fields of [`Server`], in the order they are declared.
+    pub const FIELDS: &'static [ServerBuilderFieldInfo] = &[
+        ServerBuilderFieldInfo {
+            name: "name",
+            ty: "String",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+        ServerBuilderFieldInfo {
+            name: "port",
+            ty: "u16",
+            required: true,
+            default: ::std::option::Option::None,
+            each: ::std::option::Option::None,
+            docs: "",
+        },
+    ];
+    ///Creates a builder with `name` set to the given value.
+    pub fn new(name: String) -> Self {
+        ServerBuilder {
+            name: name,
+            port: ::std::option::Option::None,
+            __builder_sources: [
+                ::std::option::Option::Some(ServerBuilderSource::Argument),
+                ::std::option::Option::None,
+            ],
+            __builder_source_label: ::std::option::Option::None,
+        }
+    }
+    ///Sets `port`.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = ::std::option::Option::Some(port);
+        self
+            .__builder_sources[1] = ::std::option::Option::Some(ServerBuilderSource::Setter {
+            method: "port",
+            label: ::std::clone::Clone::clone(&self.__builder_source_label),
+        });
+        self
+    }
+    /// Returns where the value of the field named `field` came from, or
+    /// `None` if the field has not been set, or has no such name.
+    pub fn source_of(&self, field: &str) -> ::std::option::Option<&ServerBuilderSource> {
+        match field {
+            "name" => ::std::option::Option::as_ref(&self.__builder_sources[0]),
+            "port" => ::std::option::Option::as_ref(&self.__builder_sources[1]),
+            _ => ::std::option::Option::None,
+        }
+    }
+    /// Sets the label recorded as the source of the fields set from now
+    /// on, e.g. the name of a configuration file.
+    pub fn source_label(
+        &mut self,
+        label: impl ::std::convert::Into<::std::string::String>,
+    ) -> &mut Self {
+        self
+            .__builder_source_label = ::std::option::Option::Some(
+            ::std::convert::Into::into(label),
+        );
+        self
+    }
+    ///Builds a new [`Server`] from the values set on this builder.
+    ///
+    ///# Errors
+    ///
+    ///Returns an error if any of these required fields has not been set:
+    ///
+    ///- `name`
+    ///- `port`
+    ///
+    ///Returns an error if the value of a field violates its constraints:
+    ///
+    ///- `port`: `range(min = 1)`
+    pub fn build(&mut self) -> ::std::result::Result<Server, ServerBuilderError> {
+        let name = ::std::clone::Clone::clone(&self.name);
+        let port = match &self.port {
+            ::std::option::Option::Some(inner) => ::std::clone::Clone::clone(inner),
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ServerBuilderError::MissingField {
+                        field: "port",
+                    }),
+                );
+            }
+        };
+        {
+            let inner = &port;
+            if *inner < 1 {
+                return ::std::result::Result::Err(
+                    ::std::convert::Into::into(ServerBuilderError::InvalidField {
+                        field: "port",
+                        constraint: "range(min = 1)",
+                        message: ::std::string::String::from("must be at least 1"),
+                        source: ::std::clone::Clone::clone(&self.__builder_sources[1]),
+                    }),
+                );
+            }
+        }
+        let value = Server { name: name, port: port };
+        ::std::result::Result::Ok(value)
+    }
+}
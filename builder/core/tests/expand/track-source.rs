@@ -0,0 +1,8 @@
+#[derive(Builder)]
+#[builder(track_source)]
+pub struct Server {
+    #[builder(required_arg)]
+    name: String,
+    #[builder(range(min = 1))]
+    port: u16,
+}
@@ -0,0 +1,42 @@
+// Users sometimes need the generated builder to carry attributes of its own:
+// derives such as Debug or Clone, lint attributes like #[must_use], or
+// #[doc(hidden)] on types that are not part of the public API.
+//
+// Support a struct-level #[builder(derive(...))] which is turned into a derive
+// on the builder struct, and #[builder(struct_attr(...))] whose contents are
+// emitted as attributes on the builder struct. On fields, support
+// #[builder(field_attr(...))] for attributes on the builder's storage for that
+// field and #[builder(setter_attr(...))] for attributes on its setter methods.
+//
+// The derives below only compile if the builder itself implements Debug, Clone
+// and PartialEq.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(derive(Debug, Clone, PartialEq), struct_attr(must_use, doc(hidden)))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg", field_attr(doc = "Arguments collected so far."))]
+    args: Vec<String>,
+    #[builder(setter_attr(inline), setter_attr(allow(clippy::needless_pass_by_value)))]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned());
+
+    let copy = builder.clone();
+    assert_eq!(builder, copy);
+
+    let debug = format!("{:?}", copy);
+    assert!(debug.contains("CommandBuilder"));
+    assert!(debug.contains("cargo"));
+
+    let command = builder.build().unwrap();
+    assert_eq!(command.args, vec!["build"]);
+}
@@ -0,0 +1,42 @@
+// Builders are part of a crate's public API, so everything the derive
+// generates should show up with useful documentation.
+//
+// Copy the doc comments written on each field onto its setter, document the
+// builder struct with a reference back to the type it builds, and list in the
+// documentation of build() which fields are required and what the remaining
+// fields default to.
+//
+// With #![deny(missing_docs)] this test only compiles if every public item
+// emitted by the macro carries documentation. Setters use the visibility of
+// the input struct, so they are public here and need docs of their own.
+
+#![deny(missing_docs)]
+
+//! Test crate for generated builder documentation.
+
+use derive_builder::Builder;
+
+/// A command to run.
+#[derive(Builder)]
+pub struct Command {
+    /// The program to execute.
+    pub executable: String,
+    /// Arguments passed to the program.
+    ///
+    /// Each call to `arg` appends one argument.
+    #[builder(each = "arg")]
+    pub args: Vec<String>,
+    env: Vec<String>,
+    /// The working directory, if different from the current one.
+    pub current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .env(vec![])
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+}
@@ -0,0 +1,39 @@
+// Fields can be conditionally compiled, for example only when a cargo feature
+// is enabled. The builder must not refer to such a field when its cfg is
+// disabled, so every #[cfg] on a field needs to be repeated on the builder's
+// storage for that field, its setters, its initializer in new() and its use
+// in build().
+//
+// A #[cfg_attr(predicate, cfg(...))] has the same effect as a #[cfg] and must
+// be propagated too, while other attributes behind cfg_attr are left alone.
+//
+// The predicates below use `all()`, which is always true, and `any()`, which
+// is always false, so the test does not depend on any cargo features.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Connection {
+    host: String,
+    #[cfg(any())]
+    tls_certificate: String,
+    #[cfg(all())]
+    port: u16,
+    #[cfg_attr(all(), cfg(any()))]
+    #[builder(each = "proxy")]
+    proxies: Vec<String>,
+    #[cfg_attr(all(), allow(dead_code))]
+    timeout: Option<u32>,
+}
+
+fn main() {
+    let connection = Connection::builder()
+        .host("localhost".to_owned())
+        .port(443)
+        .build()
+        .unwrap();
+
+    assert_eq!(connection.host, "localhost");
+    assert_eq!(connection.port, 443);
+    assert!(connection.timeout.is_none());
+}
@@ -0,0 +1,56 @@
+// Sometimes the value a caller has at hand is not the type the struct stores,
+// for example a port number read as a string from the command line.
+//
+// Support #[builder(field(ty = "...", build = "..."))]. The `ty` is the type
+// the builder stores for that field and the setter accepts; it is stored as is
+// rather than wrapped in an Option, starting out as its Default. The `build`
+// expression computes the struct's field when build() is called and may refer
+// to `self` (the builder) and use `?`. Its error is returned by build() as the
+// Custom variant of the builder's error type.
+//
+// Either key may be given on its own. Without `build` the stored value is
+// converted with Into; without `ty` the builder stores the usual Option of the
+// field's type and `build` decides what to do with it.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Server {
+    host: String,
+    #[builder(field(ty = "String", build = "self.port.parse()?"))]
+    port: u16,
+    #[builder(field(ty = "u8"))]
+    retries: u32,
+    #[builder(field(build = "self.name.clone().unwrap_or_else(|| self.host.clone().unwrap())"))]
+    name: String,
+}
+
+fn main() {
+    let server = Server::builder()
+        .host("localhost".to_owned())
+        .port("8080".to_owned())
+        .retries(3)
+        .build()
+        .unwrap();
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.retries, 3);
+    assert_eq!(server.name, "localhost");
+
+    let err = Server::builder()
+        .host("localhost".to_owned())
+        .port("http".to_owned())
+        .build()
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "invalid digit found in string");
+    assert!(matches!(err, ServerBuilderError::Custom(_)));
+
+    let server = Server::builder()
+        .host("localhost".to_owned())
+        .port("80".to_owned())
+        .name("web".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(server.retries, 0);
+    assert_eq!(server.name, "web");
+}
@@ -0,0 +1,44 @@
+// Configuration that lives in a `static` has to be built at compile time.
+//
+// Support a struct-level #[builder(const)] that makes builder(), new(), the
+// setters and build() into `const fn`s. Since a `const fn` cannot call
+// Clone::clone or take part in `&mut self` method chains on temporaries, the
+// setters of a const builder take and return the builder by value, and
+// build() consumes it. Missing fields are reported as a `&'static str`, which
+// the caller can pass to panic! to fail the build at compile time.
+//
+// This works for field types without a destructor, such as integers,
+// references and Options of those.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct DriverConfig {
+    name: &'static str,
+    irq: u8,
+    dma_channel: Option<u8>,
+}
+
+static UART0: DriverConfig = match DriverConfig::builder().name("uart0").irq(4).build() {
+    Ok(config) => config,
+    Err(msg) => panic!("{}", msg),
+};
+
+const INCOMPLETE: Result<DriverConfig, &str> = DriverConfig::builder().name("uart1").build();
+
+fn main() {
+    assert_eq!(UART0.name, "uart0");
+    assert_eq!(UART0.irq, 4);
+    assert!(UART0.dma_channel.is_none());
+
+    assert_eq!(INCOMPLETE.err(), Some("irq must be set"));
+
+    let config = DriverConfig::builder()
+        .name("spi0")
+        .irq(7)
+        .dma_channel(2)
+        .build()
+        .unwrap();
+    assert_eq!(config.dma_channel, Some(2));
+}
@@ -0,0 +1,15 @@
+// Repeated fields are filled in with Vec::push, which cannot be called in a
+// `const fn`. A const builder must reject #[builder(each = "...")] with an
+// error pointing at the element setter name.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct DriverConfig {
+    name: &'static str,
+    #[builder(each = "irq")]
+    irqs: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+// Builders for generic structs need to carry the same generic parameters as
+// the struct they build: lifetimes, type parameters with their bounds, const
+// parameters and where-clauses.
+//
+// The builder struct is declared with the struct's generic parameters, and
+// each impl block uses them via Generics::split_for_impl.
+//
+//
+// Resources:
+//
+//   - Splitting generics for an impl block:
+//     https://docs.rs/syn/1.0/syn/struct.Generics.html#method.split_for_impl
+
+use derive_builder::Builder;
+use std::fmt::Debug;
+
+#[derive(Builder)]
+pub struct Request<'a, T: Clone, const N: usize>
+where
+    T: Debug,
+{
+    path: &'a str,
+    body: T,
+    #[builder(each = "header")]
+    headers: Vec<[u8; N]>,
+    timeout: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct Limits<T: Copy> {
+    lower: T,
+    upper: T,
+}
+
+const PERCENT: Limits<u8> = match Limits::builder().lower(0).upper(100).build() {
+    Ok(limits) => limits,
+    Err(msg) => panic!("{}", msg),
+};
+
+fn main() {
+    let path = String::from("/index.html");
+    let request = Request::builder()
+        .path(&path)
+        .body(vec![1, 2, 3])
+        .header([0; 4])
+        .build()
+        .unwrap();
+
+    assert_eq!(request.path, "/index.html");
+    assert_eq!(request.body, vec![1, 2, 3]);
+    assert_eq!(request.headers, vec![[0; 4]]);
+    assert!(request.timeout.is_none());
+
+    assert_eq!(PERCENT.upper, 100);
+}
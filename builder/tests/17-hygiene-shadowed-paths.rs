@@ -0,0 +1,40 @@
+// Generated code refers to standard library items through absolute paths such
+// as ::std::option::Option, so it keeps working when the caller's module
+// declares its own items named `std` or `core`, or shadows common prelude
+// names. See also 09-redefined-prelude-types.rs.
+
+use derive_builder::Builder;
+
+#[allow(dead_code)]
+mod std {}
+
+#[allow(dead_code)]
+mod core {}
+
+#[allow(dead_code)]
+struct Default;
+
+#[allow(dead_code)]
+trait Into {}
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(field(ty = "u8"))]
+    retries: u32,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .retries(2)
+        .arg("build".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.retries, 2);
+    assert_eq!(command.args, vec!["build"]);
+}
@@ -0,0 +1,13 @@
+// Struct-level builder options are checked just like field-level ones: an
+// unknown option is reported at the attribute's arguments, listing the
+// options that are accepted.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(derives(Debug))]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {}
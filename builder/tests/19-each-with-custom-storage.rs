@@ -0,0 +1,14 @@
+// An element setter pushes onto a Vec stored in the builder, which cannot work
+// when the builder stores the field as some other type. Combining `each` with
+// `field(ty = "...")` is an error that points at both of them.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg", field(ty = "String"))]
+    args: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// The arguments of #[builder(field(...))] are parsed when the derive runs, so
+// a mistake such as an unknown key is reported where it was written instead of
+// somewhere in the generated code.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Server {
+    #[builder(field(type = "String"))]
+    host: String,
+}
+
+fn main() {}
@@ -0,0 +1,61 @@
+// The generated build method declares local variables and match bindings of
+// its own. Those must not collide with field names, so they are created with
+// mixed-site hygiene, which makes them invisible to code written by the user.
+//
+// Methods of standard traits are called with fully qualified syntax such as
+// ::std::clone::Clone::clone(&value). Method call syntax would become
+// ambiguous when the caller has a trait in scope that adds a method of the
+// same name to every type.
+
+use derive_builder::Builder;
+
+pub trait Sneaky {
+    fn clone(&self) -> Self;
+    fn into(self) -> Self;
+}
+
+impl<T> Sneaky for T {
+    fn clone(&self) -> Self {
+        unimplemented!()
+    }
+    fn into(self) -> Self {
+        unimplemented!()
+    }
+}
+
+#[derive(Builder)]
+pub struct Adversarial {
+    inner: String,
+    self_: u32,
+    r#type: String,
+    #[builder(field(ty = "u8"))]
+    executable: u32,
+    #[builder(field(build = "self.fallback.unwrap_or(inner_default())"))]
+    fallback: u8,
+    current_dir: Option<String>,
+}
+
+fn inner_default() -> u8 {
+    7
+}
+
+fn main() {
+    let value = Adversarial::builder()
+        .inner("a".to_owned())
+        .self_(1)
+        .r#type("b".to_owned())
+        .executable(2)
+        .current_dir("..".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(value.inner, "a");
+    assert_eq!(value.self_, 1);
+    assert_eq!(value.r#type, "b");
+    assert_eq!(value.executable, 2);
+    assert_eq!(value.fallback, 7);
+    assert_eq!(value.current_dir.as_deref(), Some(".."));
+
+    let err = Adversarial::builder().build().err().unwrap();
+    assert_eq!(err.to_string(), "inner must be set");
+}
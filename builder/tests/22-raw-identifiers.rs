@@ -0,0 +1,38 @@
+// Fields may be named with raw identifiers, either because the name is a
+// keyword (`r#type`) or for no particular reason (`r#name`).
+//
+// Messages and documentation use the name without the `r#` prefix. Generated
+// method names are only raw identifiers where the plain name is a keyword, so
+// the setter for `r#name` is `name` while the one for `r#type` is `r#type`.
+// Likewise #[builder(each = "match")] produces an element setter `r#match`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Pattern {
+    r#type: String,
+    r#name: String,
+    #[builder(each = "match")]
+    r#matches: Vec<String>,
+    #[builder(each = "r#loop")]
+    r#loop: Vec<u8>,
+}
+
+fn main() {
+    let pattern = Pattern::builder()
+        .r#type("glob".to_owned())
+        .name("sources".to_owned())
+        .r#match("*.rs".to_owned())
+        .r#match("*.toml".to_owned())
+        .r#loop(1)
+        .build()
+        .unwrap();
+
+    assert_eq!(pattern.r#type, "glob");
+    assert_eq!(pattern.name, "sources");
+    assert_eq!(pattern.matches, vec!["*.rs", "*.toml"]);
+    assert_eq!(pattern.r#loop, vec![1]);
+
+    let err = Pattern::builder().build().err().unwrap();
+    assert_eq!(err.to_string(), "type must be set");
+}
@@ -0,0 +1,12 @@
+// Names that cannot be used for a method even as a raw identifier, like
+// `self` or `crate`, are rejected where they are written.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "self")]
+    args: Vec<String>,
+}
+
+fn main() {}
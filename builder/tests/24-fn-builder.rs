@@ -0,0 +1,83 @@
+// Functions with many parameters benefit from a builder as much as structs
+// with many fields do.
+//
+// The attribute macro #[builder] on a function generates a `<name>_builder()`
+// function returning a builder with one setter per argument, and a `call()`
+// method on the builder that calls the function with the arguments that were
+// set. Arguments accept the same #[builder(...)] options as struct fields, for
+// example `default` for arguments that may be left out.
+//
+// For methods, put #[builder] on the impl block as well as on each method that
+// should get a builder. The builder is created with `Type::<name>_builder()`,
+// and the receiver, if any, is passed to `call`.
+//
+// Missing required arguments are reported by `call()` the same way `build()`
+// reports missing fields.
+
+use derive_builder::builder;
+
+#[builder]
+fn connect(host: &str, port: u16, #[builder(default = 30)] timeout: u32) -> String {
+    format!("{}:{} ({}s)", host, port, timeout)
+}
+
+pub struct Client {
+    prefix: String,
+}
+
+#[builder]
+impl Client {
+    #[builder]
+    pub fn new(prefix: String, #[builder(default)] verbose: bool) -> Client {
+        assert!(!verbose);
+        Client { prefix }
+    }
+
+    #[builder]
+    fn request(&self, path: &str, #[builder(each = "header")] headers: Vec<String>) -> String {
+        format!("{}{} {:?}", self.prefix, path, headers)
+    }
+
+    #[builder(derive(Debug))]
+    fn set_prefix(&mut self, prefix: String) {
+        self.prefix = prefix;
+    }
+}
+
+fn main() {
+    let address = connect_builder()
+        .host("localhost")
+        .port(8080)
+        .call()
+        .unwrap();
+    assert_eq!(address, "localhost:8080 (30s)");
+
+    let address = connect_builder()
+        .host("localhost")
+        .port(22)
+        .timeout(5)
+        .call()
+        .unwrap();
+    assert_eq!(address, "localhost:22 (5s)");
+
+    let err = connect_builder().host("localhost").call().unwrap_err();
+    assert_eq!(err.to_string(), "port must be set");
+
+    let mut client = Client::new_builder()
+        .prefix("/api".to_owned())
+        .call()
+        .unwrap();
+
+    let response = Client::request_builder()
+        .path("/users")
+        .header("Accept: */*".to_owned())
+        .call(&client)
+        .unwrap();
+    assert_eq!(response, "/api/users [\"Accept: */*\"]");
+
+    let mut builder = Client::set_prefix_builder();
+    builder.prefix("/v2".to_owned());
+    assert!(format!("{:?}", builder).contains("/v2"));
+    builder.call(&mut client).unwrap();
+    assert_eq!(client.prefix, "/v2");
+}
@@ -0,0 +1,41 @@
+// Fields that may be left unset can be given a default value with
+// #[builder(default = ...)]. A string holds an expression that is evaluated
+// when build() is called; any other literal is used as is. A bare
+// #[builder(default)] uses the field type's Default implementation.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Server {
+    host: String,
+    #[builder(default = 8080)]
+    port: u16,
+    #[builder(default = "format!(\"{}.log\", \"server\")")]
+    log_file: String,
+    #[builder(default)]
+    workers: Vec<String>,
+    #[builder(default = "Some(3)")]
+    retries: Option<u8>,
+}
+
+fn main() {
+    let server = Server::builder()
+        .host("localhost".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.log_file, "server.log");
+    assert!(server.workers.is_empty());
+    assert_eq!(server.retries, Some(3));
+
+    let server = Server::builder()
+        .host("localhost".to_owned())
+        .port(80)
+        .retries(1)
+        .build()
+        .unwrap();
+
+    assert_eq!(server.port, 80);
+    assert_eq!(server.retries, Some(1));
+}
@@ -0,0 +1,79 @@
+// Some default values can only be obtained asynchronously, for example by
+// reading a file or asking a local service.
+//
+// With #[builder(build_fn(async))] the generated build() is an `async fn`.
+// Fields may then use #[builder(default_async = "...")], an expression that is
+// awaited when the field has not been set. build_fn also accepts a `validate`
+// function, which is given the built value and returns a Result; in an async
+// builder the validator is an `async fn` and is awaited too. Its error is
+// returned as the Custom variant of the builder's error type.
+//
+// The futures here complete immediately, so the test drives them with a
+// minimal executor instead of depending on an async runtime.
+
+use derive_builder::Builder;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+async fn default_port() -> u16 {
+    8080
+}
+
+async fn lookup_name(host: &str) -> String {
+    format!("{}.local", host)
+}
+
+async fn check_config(config: &Config) -> Result<(), String> {
+    if config.port == 0 {
+        return Err("port must not be 0".to_owned());
+    }
+    Ok(())
+}
+
+#[derive(Builder)]
+#[builder(build_fn(async, validate = "check_config"))]
+pub struct Config {
+    host: String,
+    #[builder(default_async = "default_port()")]
+    port: u16,
+    #[builder(default_async = "lookup_name(\"server\")")]
+    name: String,
+    #[builder(default = 3)]
+    retries: u8,
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn main() {
+    let config = block_on(Config::builder().host("localhost".to_owned()).build()).unwrap();
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.name, "server.local");
+    assert_eq!(config.retries, 3);
+
+    let config = block_on(
+        Config::builder()
+            .host("localhost".to_owned())
+            .port(443)
+            .build(),
+    )
+    .unwrap();
+    assert_eq!(config.port, 443);
+
+    let err = block_on(Config::builder().host("localhost".to_owned()).port(0).build());
+    let err = err.err().unwrap();
+    assert_eq!(err.to_string(), "port must not be 0");
+    assert!(matches!(err, ConfigBuilderError::Custom(_)));
+
+    let err = block_on(Config::builder().build());
+    assert_eq!(err.err().unwrap().to_string(), "host must be set");
+}
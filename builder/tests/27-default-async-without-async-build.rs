@@ -0,0 +1,11 @@
+// An awaited default only makes sense in an async build method.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Config {
+    #[builder(default_async = "default_port()")]
+    port: u16,
+}
+
+fn main() {}
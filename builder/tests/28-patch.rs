@@ -0,0 +1,85 @@
+// PATCH-style updates change some fields of an existing value and leave the
+// others alone.
+//
+// With #[builder(patch)] the derive also generates a `<Struct>Patch` type with
+// an Option of each field's type, `<Struct>::apply` which overwrites the fields
+// that are Some in a patch, and `<Builder>::build_partial` which returns the
+// fields set on a builder as a patch, without requiring any field to be set.
+//
+// An optional field of type Option<T> becomes Option<Option<T>> in the patch,
+// so a patch can also reset such a field to None.
+//
+// Fields left unset on the builder stay None in the patch, even those with a
+// default, so applying it never resets them.
+//
+// A patch is Default for any type parameters, since all of its fields are
+// Options.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(patch)]
+pub struct User {
+    name: String,
+    email: String,
+    #[builder(each = "role")]
+    roles: Vec<String>,
+    nickname: Option<String>,
+    #[builder(default = "3")]
+    retries: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoDefault(u8);
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(patch)]
+pub struct Wrap<T: Clone> {
+    value: T,
+    count: u32,
+}
+
+fn main() {
+    let mut user = User::builder()
+        .name("Ada".to_owned())
+        .email("ada@example.com".to_owned())
+        .role("admin".to_owned())
+        .nickname("countess".to_owned())
+        .retries(5)
+        .build()
+        .unwrap();
+
+    let patch = User::builder()
+        .email("ada@lovelace.org".to_owned())
+        .build_partial();
+    assert_eq!(patch.name, None);
+    assert_eq!(patch.roles, None);
+    assert_eq!(patch.nickname, None);
+    assert_eq!(patch.retries, None);
+
+    user.apply(patch);
+    assert_eq!(user.name, "Ada");
+    assert_eq!(user.email, "ada@lovelace.org");
+    assert_eq!(user.roles, vec!["admin"]);
+    assert_eq!(user.nickname.as_deref(), Some("countess"));
+    assert_eq!(user.retries, 5);
+
+    user.apply(UserPatch {
+        roles: Some(vec![]),
+        nickname: Some(None),
+        ..UserPatch::default()
+    });
+    assert!(user.roles.is_empty());
+    assert_eq!(user.nickname, None);
+
+    let mut wrap = Wrap::builder()
+        .value(NoDefault(1))
+        .count(1)
+        .build()
+        .unwrap();
+    wrap.apply(WrapPatch {
+        count: Some(2),
+        ..WrapPatch::<NoDefault>::default()
+    });
+    assert_eq!(wrap, Wrap { value: NoDefault(1), count: 2 });
+}
@@ -0,0 +1,109 @@
+// Fields can carry constraints on their value, which build() checks once all
+// fields are known:
+//
+//   #[builder(range(min = 1, max = 65535))]   min and max are both optional
+//   #[builder(non_empty)]                     any type with an is_empty method
+//   #[builder(validate = "path")]             a fn(&T) -> Result<(), E: Display>
+//
+// A bound that is not a plain number, such as a negative number or a constant,
+// is written as an expression in a string: range(min = "-10").
+//
+// The constraints of an Option field apply to the value it holds, if any.
+//
+// A violated constraint is reported as the InvalidField variant of the
+// generated <Builder>Error type, naming the field and the constraint, and a
+// field that has not been set as MissingField. build() returns that error
+// type, so callers can match on its variants.
+
+use derive_builder::Builder;
+
+const LOWEST_OFFSET: i32 = -12;
+
+fn not_reserved(name: &String) -> Result<(), String> {
+    if name == "root" {
+        Err(format!("`{}` is reserved", name))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Builder, Debug)]
+pub struct Account {
+    #[builder(non_empty, validate = "not_reserved")]
+    name: String,
+    #[builder(range(min = 1, max = 65535))]
+    port: u16,
+    #[builder(each = "group", non_empty)]
+    groups: Vec<String>,
+    #[builder(range(min = "LOWEST_OFFSET", max = 14))]
+    utc_offset: Option<i32>,
+    #[builder(default, range(min = "-10", max = 40))]
+    temperature: i8,
+}
+
+fn main() {
+    let account = Account::builder()
+        .name("ada".to_owned())
+        .port(22)
+        .group("admin".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(account.name, "ada");
+    assert_eq!(account.utc_offset, None);
+
+    let mut builder = Account::builder();
+    builder.name("root".to_owned()).port(22).group("admin".to_owned());
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.to_string(), "invalid name: `root` is reserved");
+    assert!(matches!(
+        err,
+        AccountBuilderError::InvalidField {
+            field: "name",
+            constraint: "validate = \"not_reserved\"",
+            ref message,
+        } if message == "`root` is reserved"
+    ));
+
+    builder.name(String::new());
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.to_string(), "invalid name: must not be empty");
+
+    builder.name("ada".to_owned()).port(0);
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.to_string(), "invalid port: must be at least 1");
+
+    builder.port(22).utc_offset(-13);
+    let err = builder.build().unwrap_err();
+    assert!(matches!(
+        err,
+        AccountBuilderError::InvalidField {
+            field: "utc_offset",
+            constraint: "range(min = LOWEST_OFFSET, max = 14)",
+            ref message,
+        } if message == "must be at least LOWEST_OFFSET"
+    ));
+
+    builder.utc_offset(2).temperature(-11);
+    let err = builder.build().unwrap_err();
+    assert!(matches!(
+        err,
+        AccountBuilderError::InvalidField {
+            field: "temperature",
+            constraint: "range(min = -10, max = 40)",
+            ref message,
+        } if message == "must be at least -10"
+    ));
+
+    let err = Account::builder().port(22).build().unwrap_err();
+    assert!(matches!(
+        err,
+        AccountBuilderError::MissingField { field: "name" }
+    ));
+
+    let err = Account::builder()
+        .name("ada".to_owned())
+        .port(22)
+        .build()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "invalid groups: must not be empty");
+}
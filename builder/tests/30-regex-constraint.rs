@@ -0,0 +1,33 @@
+// With the `regex` feature, #[builder(regex = "...")] checks that a field's
+// value matches a pattern. The pattern is checked when the derive runs, and
+// compiled once, the first time build() needs it. The generated code calls
+// the regex crate, so the crate using the derive depends on it too.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Host {
+    #[builder(regex = "^[a-z0-9-]+(\\.[a-z0-9-]+)*$")]
+    name: String,
+}
+
+fn main() {
+    let host = Host::builder()
+        .name("example.org".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(host.name, "example.org");
+
+    let err = Host::builder()
+        .name("Example.org".to_owned())
+        .build()
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        HostBuilderError::InvalidField {
+            field: "name",
+            constraint: "regex = \"^[a-z0-9-]+(\\\\.[a-z0-9-]+)*$\"",
+            ref message,
+        } if message == "must match `^[a-z0-9-]+(\\.[a-z0-9-]+)*$`"
+    ));
+}
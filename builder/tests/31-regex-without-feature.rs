@@ -0,0 +1,12 @@
+// Without the `regex` feature, a regex constraint is an error pointing at the
+// pattern, rather than generated code that fails to find the regex crate.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Host {
+    #[builder(regex = "^[a-z]+$")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+// Builder setters are named after the fields of a struct, so the derive only
+// accepts structs with named fields. Enums, unions, tuple structs and unit
+// structs are rejected with an error pointing at the part of the item that
+// makes it unsupported, rather than with a panic inside the macro.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+#[derive(Builder)]
+pub union Bits {
+    int: u32,
+    float: f32,
+}
+
+#[derive(Builder)]
+pub struct Meters(f64);
+
+#[derive(Builder)]
+pub struct Marker;
+
+fn main() {}
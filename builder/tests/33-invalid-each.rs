@@ -0,0 +1,28 @@
+// An element setter pushes onto a Vec, so `each` is rejected on fields of any
+// other type. Its name must also differ from every other setter, or the
+// builder would get two methods with the same name.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "arg")]
+    args: String,
+}
+
+#[derive(Builder)]
+pub struct Request {
+    header: String,
+    #[builder(each = "header")]
+    headers: Vec<String>,
+}
+
+#[derive(Builder)]
+pub struct Mail {
+    #[builder(each = "add")]
+    to: Vec<String>,
+    #[builder(each = "add")]
+    cc: Vec<String>,
+}
+
+fn main() {}
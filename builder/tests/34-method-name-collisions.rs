@@ -0,0 +1,30 @@
+// Besides a setter per field and an element setter per `each`, the builder
+// has methods of its own: new() and build(), build_partial() with
+// #[builder(patch)], and call() for the builder of a function. Any two of
+// these with the same name are reported up front, pointing at the field or
+// `each` option responsible, instead of as a duplicate definition inside the
+// macro's output.
+
+use derive_builder::{builder, Builder};
+
+#[derive(Builder)]
+pub struct Pipeline {
+    build: String,
+}
+
+#[derive(Builder)]
+pub struct Queue {
+    #[builder(each = "new")]
+    jobs: Vec<String>,
+}
+
+#[derive(Builder)]
+#[builder(patch)]
+pub struct Document {
+    build_partial: bool,
+}
+
+#[builder]
+pub fn dial(number: String, call: bool) {}
+
+fn main() {}
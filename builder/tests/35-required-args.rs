@@ -0,0 +1,41 @@
+// Some types have one or two fields that identify them and must always be
+// given. With #[builder(required_arg)] such a field becomes a parameter of
+// builder(), in the order of the fields, instead of getting a setter. Since
+// the builder cannot exist without the value, build() does not check for it.
+//
+// Function builders take their required arguments in <name>_builder().
+
+use derive_builder::{builder, Builder};
+
+#[derive(Builder, Debug)]
+pub struct User {
+    #[builder(required_arg)]
+    id: u64,
+    #[builder(required_arg)]
+    name: String,
+    email: Option<String>,
+    #[builder(default)]
+    admin: bool,
+}
+
+#[builder]
+pub fn greet(#[builder(required_arg)] name: &str, excited: bool) -> String {
+    format!("Hello, {}{}", name, if excited { "!" } else { "." })
+}
+
+fn main() {
+    let user = User::builder(7, "ada".to_owned()).build().unwrap();
+    assert_eq!(user.id, 7);
+    assert_eq!(user.name, "ada");
+    assert_eq!(user.email, None);
+    assert!(!user.admin);
+
+    let mut builder = UserBuilder::new(8, "grace".to_owned());
+    builder.email("grace@example.com".to_owned()).admin(true);
+    let user = builder.build().unwrap();
+    assert_eq!(user.id, 8);
+    assert_eq!(user.email.as_deref(), Some("grace@example.com"));
+
+    let greeting = greet_builder("Ada").excited(true).call().unwrap();
+    assert_eq!(greeting, "Hello, Ada!");
+}
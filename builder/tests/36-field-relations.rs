@@ -0,0 +1,75 @@
+// Whether some fields may be set can depend on other fields. Struct-level
+// options express the common cases, and build() checks them before anything
+// else:
+//
+//   #[builder(one_of(a, b, ...))]      exactly one of the fields must be set
+//   #[builder(requires(a, b, ...))]    if `a` is set, all the others must be
+//   #[builder(conflicts(a, b, ...))]   at most one of the fields may be set
+//
+// A field filled with `each` counts as set once it holds an element. A field
+// with a `default` that has not been set counts as unset.
+//
+// Violations are reported as variants of the generated <Builder>Error naming
+// all fields involved.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(one_of(cert_path, cert_pem))]
+#[builder(requires(username, password), conflicts(username, token))]
+pub struct Connection {
+    host: String,
+    cert_path: Option<String>,
+    cert_pem: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
+    #[builder(each = "scope")]
+    token: Vec<String>,
+}
+
+fn main() {
+    let mut builder = Connection::builder();
+    builder.host("db".to_owned()).cert_path("/etc/db.pem".to_owned());
+    let connection = builder.build().unwrap();
+    assert_eq!(connection.cert_path.as_deref(), Some("/etc/db.pem"));
+
+    builder.cert_pem("-----BEGIN CERTIFICATE-----".to_owned());
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.to_string(), "only one of cert_path, cert_pem can be set");
+    assert!(matches!(
+        err,
+        ConnectionBuilderError::ConflictingFields {
+            fields: ["cert_path", "cert_pem"],
+        }
+    ));
+
+    let err = Connection::builder()
+        .host("db".to_owned())
+        .build()
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ConnectionBuilderError::NoneSet {
+            fields: ["cert_path", "cert_pem"],
+        }
+    ));
+
+    let mut builder = Connection::builder();
+    builder
+        .host("db".to_owned())
+        .cert_path("/etc/db.pem".to_owned())
+        .username("ada".to_owned());
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.to_string(), "username requires password to be set");
+    assert!(matches!(
+        err,
+        ConnectionBuilderError::MissingDependency {
+            field: "username",
+            requires: "password",
+        }
+    ));
+
+    builder.password("secret".to_owned()).scope("read".to_owned());
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.to_string(), "only one of username, token can be set");
+}
@@ -0,0 +1,21 @@
+// The fields named by one_of, requires and conflicts must exist, and whether
+// they are set must be visible on the builder.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(one_of(cert_path, cert))]
+pub struct Tls {
+    cert_path: Option<String>,
+    cert_pem: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(requires(user, id))]
+pub struct Session {
+    #[builder(required_arg)]
+    id: u64,
+    user: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+// Builders for credentials must not print them. A field marked
+// #[builder(secret)] shows as "<redacted>" in the Debug output of a builder
+// with #[builder(derive(Debug))], and errors from its `validate` function are
+// replaced by a message naming the function, since they may quote the value.
+// The built-in constraints never include a field's value in their errors.
+//
+// The default of a secret field is left out of the builder's FIELDS metadata,
+// of its command-line help and of the documentation of build().
+
+use derive_builder::Builder;
+
+fn strong(password: &String) -> Result<(), String> {
+    if password.len() < 8 {
+        Err(format!("`{}` is too short", password))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Builder)]
+#[builder(derive(Debug, Clone))]
+pub struct Credentials {
+    username: String,
+    #[builder(secret, validate = "strong")]
+    password: String,
+    #[builder(secret)]
+    token: Option<String>,
+    #[builder(secret, default = "\"guest-key\".to_owned()")]
+    api_key: String,
+}
+
+fn main() {
+    let mut builder = Credentials::builder();
+    builder
+        .username("ada".to_owned())
+        .password("hunter2".to_owned())
+        .token("t0ken".to_owned());
+
+    let debug = format!("{:?}", builder.clone());
+    assert_eq!(
+        debug,
+        r#"CredentialsBuilder { username: Some("ada"), password: "<redacted>", token: "<redacted>", api_key: "<redacted>" }"#
+    );
+    assert_eq!(CredentialsBuilder::FIELDS[3].name, "api_key");
+    assert_eq!(CredentialsBuilder::FIELDS[3].default, None);
+
+    let err = builder.build().err().unwrap();
+    assert_eq!(err.to_string(), "invalid password: rejected by `strong`");
+    assert!(!format!("{:?}", err).contains("hunter2"));
+
+    builder.password("correct horse".to_owned());
+    let credentials = builder.build().unwrap();
+    assert_eq!(credentials.password, "correct horse");
+    assert_eq!(credentials.api_key, "guest-key");
+}
@@ -0,0 +1,95 @@
+// A builder filled from several layers of configuration can tell where each
+// value came from. With #[builder(track_source)] the builder records, for every
+// field, a <Builder>Source: Argument for a `required_arg`, or Setter with the
+// name of the method that set it and the label last given to source_label(),
+// if any. source_of("port") returns the source of a field, or None if it has
+// not been set.
+//
+// The source type implements the common derivable traits, so that the builder
+// may derive them too.
+//
+// Errors for a field that violates a constraint include the source of the
+// rejected value.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(track_source, derive(Debug, Clone, PartialEq, Eq, Hash))]
+pub struct Server {
+    #[builder(required_arg)]
+    name: String,
+    #[builder(range(min = 1), alias = "listen_port")]
+    port: u16,
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+    workers: Option<usize>,
+}
+
+fn main() {
+    let mut builder = Server::builder("api".to_owned());
+    assert_eq!(builder.source_of("name"), Some(&ServerBuilderSource::Argument));
+    assert_eq!(builder.source_of("port"), None);
+    assert_eq!(builder.source_of("no_such_field"), None);
+
+    builder.port(80).tag("web".to_owned());
+    assert_eq!(
+        builder.source_of("port"),
+        Some(&ServerBuilderSource::Setter {
+            method: "port",
+            label: None,
+        })
+    );
+    assert_eq!(builder.source_of("port").unwrap().to_string(), "port()");
+
+    #[allow(deprecated)]
+    builder.listen_port(81);
+    assert_eq!(
+        builder.source_of("port"),
+        Some(&ServerBuilderSource::Setter {
+            method: "listen_port",
+            label: None,
+        })
+    );
+
+    builder.source_label("/etc/server.toml").port(8080);
+    builder.source_label("SERVER_WORKERS").workers(4);
+    assert_eq!(
+        builder.source_of("port"),
+        Some(&ServerBuilderSource::Setter {
+            method: "port",
+            label: Some("/etc/server.toml".to_owned()),
+        })
+    );
+    assert_eq!(
+        builder.source_of("tags"),
+        Some(&ServerBuilderSource::Setter {
+            method: "tag",
+            label: None,
+        })
+    );
+    assert_eq!(
+        builder.source_of("workers").unwrap().to_string(),
+        "SERVER_WORKERS"
+    );
+    let copy = builder.clone();
+    assert_eq!(copy, builder);
+    assert!(std::collections::HashSet::from([copy]).contains(&builder));
+    let server = builder.build().unwrap();
+    assert_eq!(server.port, 8080);
+
+    builder.source_label("--port").port(0);
+    let err = builder.build().err().unwrap();
+    assert_eq!(err.to_string(), "invalid port (from --port): must be at least 1");
+    assert!(matches!(
+        err,
+        ServerBuilderError::InvalidField {
+            field: "port",
+            constraint: "range(min = 1)",
+            ref message,
+            source: Some(ServerBuilderSource::Setter {
+                method: "port",
+                label: Some(ref label),
+            }),
+        } if message == "must be at least 1" && label == "--port"
+    ));
+}
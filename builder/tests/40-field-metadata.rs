@@ -0,0 +1,57 @@
+// Tools such as `--help` output or generated configuration documentation need
+// to know a builder's fields at runtime. Every builder has an associated
+// constant FIELDS with one <Builder>FieldInfo per field, in declaration order,
+// giving its name, its type as written, whether it must be given, the text of
+// its `default`, the name of its `each` setter, and its documentation.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Server {
+    /// The host name to listen on.
+    host: String,
+    /// The port to listen on.
+    ///
+    /// Ports below 1024 need elevated privileges.
+    #[builder(default = 8080)]
+    port: u16,
+    #[builder(each = "route")]
+    routes: Vec<(String, &'static str)>,
+    log_file: Option<std::path::PathBuf>,
+    #[cfg(any())]
+    never: u8,
+}
+
+fn main() {
+    let fields = ServerBuilder::FIELDS;
+    assert_eq!(fields.len(), 4);
+
+    assert_eq!(
+        fields[0],
+        ServerBuilderFieldInfo {
+            name: "host",
+            ty: "String",
+            required: true,
+            default: None,
+            each: None,
+            docs: "The host name to listen on.",
+        }
+    );
+    assert_eq!(fields[1].default, Some("8080"));
+    assert!(!fields[1].required);
+    assert_eq!(
+        fields[1].docs,
+        "The port to listen on.\n\nPorts below 1024 need elevated privileges."
+    );
+    assert_eq!(fields[2].ty, "Vec<(String, &'static str)>");
+    assert_eq!(fields[2].each, Some("route"));
+    assert_eq!(fields[3].ty, "Option<std::path::PathBuf>");
+    assert!(!fields[3].required);
+
+    let required: Vec<&str> = ServerBuilder::FIELDS
+        .iter()
+        .filter(|field| field.required)
+        .map(|field| field.name)
+        .collect();
+    assert_eq!(required, ["host"]);
+}
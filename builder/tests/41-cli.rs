@@ -0,0 +1,171 @@
+// With the `cli` feature, #[builder(cli)] lets a small tool use its
+// configuration struct as its command-line definition. parse_args() takes the
+// arguments without the program name and calls the setter of each option:
+//
+//   --log-file server.log   or   --log-file=server.log
+//
+// Options are named after the setters with underscores replaced by dashes, and
+// their values are parsed with FromStr. A `bool` option is a flag that may be
+// given without a value. A field with `each` is set only through its element
+// setter, which may be repeated. help() describes the options, using the first
+// line of each field's documentation, and notes whether it is required or has
+// a default, whose value is shown unless it comes from the Default trait. The
+// aliases of a field are accepted as further names of its option, but are not
+// shown in the help.
+//
+// The value of a field with setter(wrap) is parsed as what its setter takes,
+// e.g. a u32 for a Box<u32>, or a String for an Arc<str>.
+//
+// The help does not show the default of a secret field.
+//
+// With track_source, parse_args is recorded as the method that set a field.
+//
+// A field whose type cannot be parsed from a single value is left out of the
+// options with #[builder(cli(skip))], and set through its setter instead.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(cli)]
+pub struct Config {
+    /// Address to listen on.
+    #[builder(alias = "bind")]
+    host: String,
+    /// Port to listen on.
+    #[builder(default = 8080)]
+    port: u16,
+    /// Log file, if any.
+    log_file: Option<String>,
+    /// Print every request.
+    #[builder(default)]
+    verbose: bool,
+    /// Path to serve.
+    #[builder(each = "route")]
+    routes: Vec<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(cli)]
+pub struct Upload {
+    #[builder(setter(wrap))]
+    source: std::path::PathBuf,
+    #[builder(setter(wrap))]
+    bucket: std::sync::Arc<str>,
+    #[builder(setter(wrap))]
+    retries: Option<Box<u32>>,
+    /// Access token.
+    #[builder(secret, default = "\"anonymous\".to_owned()")]
+    token: String,
+}
+
+#[derive(Builder, Debug)]
+#[builder(cli, track_source)]
+pub struct Limits {
+    connections: u32,
+    #[builder(cli(skip), default = "std::time::Duration::from_secs(30)")]
+    timeout: std::time::Duration,
+}
+
+fn main() {
+    let args = [
+        "--host",
+        "localhost",
+        "--port=9000",
+        "--verbose",
+        "--route",
+        "/",
+        "--route=/api",
+        "--log-file",
+        "server.log",
+    ];
+    let config = Config::builder()
+        .parse_args(args)
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 9000);
+    assert_eq!(config.log_file.as_deref(), Some("server.log"));
+    assert!(config.verbose);
+    assert_eq!(config.routes, ["/", "/api"]);
+
+    let config = Config::builder()
+        .parse_args(["--bind", "0.0.0.0"])
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(config.host, "0.0.0.0");
+
+    let mut builder = Config::builder();
+    let err = builder.parse_args(["--port", "http"]).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "invalid value for `--port`: invalid digit found in string"
+    );
+    let err = builder.parse_args(["--host"]).err().unwrap();
+    assert_eq!(err, ConfigBuilderArgsError::MissingValue { option: "host" });
+    let err = builder.parse_args(["--verbose=yes"]).err().unwrap();
+    assert_eq!(err.to_string(), "invalid value for `--verbose`: expected `true` or `false`");
+    let err = builder.parse_args(["serve"]).err().unwrap();
+    assert_eq!(err.to_string(), "unknown argument `serve`");
+    let err = builder.parse_args(["--hots", "localhost"]).err().unwrap();
+    assert_eq!(err.to_string(), "unknown argument `--hots`");
+    let err = builder.parse_args(["--help"]).err().unwrap();
+    assert_eq!(err, ConfigBuilderArgsError::Help);
+
+    assert_eq!(
+        ConfigBuilder::help(),
+        "\
+Options:
+  --host <String>      Address to listen on. [required]
+  --port <u16>         Port to listen on. [default: 8080]
+  --log-file <String>  Log file, if any.
+  --verbose            Print every request. [default]
+  --route <String>     Path to serve. [may be repeated]
+  --help               Prints this help.
+"
+    );
+
+    let upload = Upload::builder()
+        .parse_args(["--source", "photo.jpg", "--bucket=media", "--retries", "3"])
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(upload.source, std::path::Path::new("photo.jpg"));
+    assert_eq!(&*upload.bucket, "media");
+    assert_eq!(upload.retries, Some(Box::new(3)));
+    assert_eq!(upload.token, "anonymous");
+    assert_eq!(
+        UploadBuilder::help(),
+        "\
+Options:
+  --source <std::path::PathBuf>  [required]
+  --bucket <String>              [required]
+  --retries <u32>
+  --token <String>               Access token. [default]
+  --help                         Prints this help.
+"
+    );
+
+    let mut builder = Limits::builder();
+    builder.source_label("argv").parse_args(["--connections=64"]).unwrap();
+    assert_eq!(
+        builder.source_of("connections"),
+        Some(&LimitsBuilderSource::Setter {
+            method: "parse_args",
+            label: Some("argv".to_owned()),
+        })
+    );
+    let err = builder.parse_args(["--timeout", "60"]).err().unwrap();
+    assert_eq!(err.to_string(), "unknown argument `--timeout`");
+    let limits = builder.build().unwrap();
+    assert_eq!(limits.timeout, std::time::Duration::from_secs(30));
+    assert_eq!(
+        LimitsBuilder::help(),
+        "\
+Options:
+  --connections <u32>  [required]
+  --help               Prints this help.
+"
+    );
+}
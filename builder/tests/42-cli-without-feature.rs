@@ -0,0 +1,11 @@
+// Without the `cli` feature, #[builder(cli)] is reported where it is written.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(cli)]
+pub struct Config {
+    host: String,
+}
+
+fn main() {}
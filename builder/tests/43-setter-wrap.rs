@@ -0,0 +1,72 @@
+// Fields holding a smart pointer or a path make every call site wrap the value
+// first, as in `.config(Arc::new(config))`. With #[builder(setter(wrap))] the
+// setter does the wrapping, depending on the type of the field:
+//
+//   Box<T>, Arc<T>, Rc<T>   the setter takes a T and calls new
+//   Arc<str>, Cow<'a, B>    the setter takes anything that converts with Into,
+//                           such as a &str or a String
+//   PathBuf                 the setter takes any impl AsRef<Path>
+//
+// An Option of one of these types is wrapped the same way before being put in
+// the Option.
+
+use derive_builder::Builder;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    verbose: bool,
+}
+
+#[derive(Builder, Debug)]
+pub struct Server<'a> {
+    #[builder(setter(wrap))]
+    settings: Arc<Settings>,
+    #[builder(setter(wrap))]
+    name: Arc<str>,
+    #[builder(setter(wrap))]
+    greeting: Cow<'a, str>,
+    #[builder(setter(wrap))]
+    root: PathBuf,
+    #[builder(setter(wrap))]
+    log: Option<PathBuf>,
+    #[builder(setter(wrap))]
+    counter: Rc<u32>,
+    #[builder(setter(wrap))]
+    overrides: Option<Box<Settings>>,
+}
+
+fn main() {
+    let greeting = String::from("hello");
+    let server = Server::builder()
+        .settings(Settings { verbose: true })
+        .name("main")
+        .greeting(greeting.as_str())
+        .root("/srv")
+        .log(Path::new("/var/log/main.log"))
+        .counter(1)
+        .build()
+        .unwrap();
+    assert_eq!(*server.settings, Settings { verbose: true });
+    assert_eq!(&*server.name, "main");
+    assert!(matches!(server.greeting, Cow::Borrowed("hello")));
+    assert_eq!(server.root, PathBuf::from("/srv"));
+    assert_eq!(server.log, Some(PathBuf::from("/var/log/main.log")));
+    assert_eq!(*server.counter, 1);
+    assert!(server.overrides.is_none());
+
+    let server = Server::builder()
+        .settings(Settings { verbose: false })
+        .name(String::from("backup"))
+        .greeting(String::from("hi"))
+        .root(PathBuf::from("/backup"))
+        .counter(2)
+        .overrides(Settings { verbose: true })
+        .build()
+        .unwrap();
+    assert!(matches!(server.greeting, Cow::Owned(_)));
+    assert_eq!(server.overrides, Some(Box::new(Settings { verbose: true })));
+}
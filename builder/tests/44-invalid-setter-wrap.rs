@@ -0,0 +1,26 @@
+// `setter(wrap)` only knows how to wrap a value into the types listed in
+// 43-setter-wrap.rs. A pointer to a trait object cannot be created from the
+// value either, since the setter cannot name the value's concrete type.
+
+use derive_builder::Builder;
+use std::fmt::Display;
+
+#[derive(Builder)]
+pub struct Label {
+    #[builder(setter(wrap))]
+    text: String,
+}
+
+#[derive(Builder)]
+pub struct Message {
+    #[builder(setter(wrap))]
+    body: Box<dyn Display>,
+}
+
+#[derive(Builder)]
+pub struct Queue {
+    #[builder(each = "item", setter(wrap))]
+    items: Vec<Box<u32>>,
+}
+
+fn main() {}
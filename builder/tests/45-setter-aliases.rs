@@ -0,0 +1,46 @@
+// When a field is renamed, callers using the old setter name should keep
+// compiling for a while. #[builder(alias = "old_name")] adds a setter with the
+// old name that forwards to the field's setter. It is marked #[deprecated],
+// with the note given by `deprecated = "..."`, or "renamed to `field`".
+//
+// A field may have several aliases. With the `cli` feature, parse_args also
+// accepts an alias as the name of the field's option.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Connection {
+    #[builder(alias = "hostname", deprecated = "use `host` instead")]
+    host: String,
+    #[builder(alias = "timeout_secs", alias = "timeout")]
+    timeout_seconds: u64,
+    #[builder(alias = "ca_file")]
+    certificate: Option<String>,
+}
+
+#[allow(deprecated)]
+fn old_style() -> Connection {
+    Connection::builder()
+        .hostname("db.local".to_owned())
+        .timeout(30)
+        .ca_file("ca.pem".to_owned())
+        .build()
+        .unwrap()
+}
+
+fn main() {
+    let connection = old_style();
+    assert_eq!(connection.host, "db.local");
+    assert_eq!(connection.timeout_seconds, 30);
+    assert_eq!(connection.certificate.as_deref(), Some("ca.pem"));
+
+    #[allow(deprecated)]
+    let connection = Connection::builder()
+        .host("db.remote".to_owned())
+        .timeout_secs(5)
+        .build()
+        .unwrap();
+    assert_eq!(connection.host, "db.remote");
+    assert_eq!(connection.timeout_seconds, 5);
+    assert_eq!(connection.certificate, None);
+}
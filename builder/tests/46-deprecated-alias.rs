@@ -0,0 +1,21 @@
+// Calling an alias setter triggers the `deprecated` lint, showing the note
+// given with the alias, so that callers find out about the new name.
+
+#![deny(deprecated)]
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Connection {
+    #[builder(alias = "hostname", deprecated = "use `host` instead")]
+    host: String,
+    #[builder(alias = "timeout")]
+    timeout_seconds: u64,
+}
+
+fn main() {
+    let _ = Connection::builder()
+        .hostname("db.local".to_owned())
+        .timeout(30)
+        .build();
+}
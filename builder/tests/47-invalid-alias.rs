@@ -0,0 +1,26 @@
+// An alias adds a setter, so it must not take the name of another method of
+// the builder, and needs a field that has a setter to forward to. The
+// `deprecated` note belongs to the aliases, so it needs at least one.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Window {
+    width: u32,
+    #[builder(alias = "width")]
+    height: u32,
+}
+
+#[derive(Builder)]
+pub struct Label {
+    #[builder(required_arg, alias = "caption")]
+    text: String,
+}
+
+#[derive(Builder)]
+pub struct Button {
+    #[builder(deprecated = "use `label` instead")]
+    label: String,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+// A patch holds the fields set on a builder, so `patch` cannot be combined with
+// fields whose builder storage cannot say whether they were set: a custom
+// storage type from field(ty) is filled with its default from the start, and a
+// field(build) expression may read any other field.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(patch)]
+pub struct Mailbox {
+    owner: String,
+    #[builder(field(ty = "Vec<String>"))]
+    aliases: Vec<String>,
+}
+
+#[derive(Builder)]
+#[builder(patch)]
+pub struct Quota {
+    limit: u64,
+    #[builder(field(build = "self.limit.unwrap_or(0) / 10"))]
+    warn_at: u64,
+}
+
+fn main() {}
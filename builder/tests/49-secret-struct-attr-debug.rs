@@ -0,0 +1,14 @@
+// A Debug derive forwarded with struct_attr would print the secret fields of
+// the builder, so it is rejected; #[builder(derive(Debug))] redacts them.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(struct_attr(derive(Clone, Debug)))]
+pub struct Credentials {
+    username: String,
+    #[builder(secret)]
+    password: String,
+}
+
+fn main() {}
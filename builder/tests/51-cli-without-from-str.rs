@@ -0,0 +1,30 @@
+// parse_args() parses every option with FromStr, so a field whose type does
+// not implement it is reported at that type. Such a field can be set through
+// its setter only, with #[builder(cli(skip))], as tests/41-cli.rs does.
+//
+// cli(skip) has no meaning without #[builder(cli)] on the struct, and is
+// rejected there.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(cli)]
+pub struct Config {
+    host: String,
+    tags: Vec<String>,
+}
+
+#[derive(Builder)]
+pub struct Plain {
+    #[builder(cli(skip))]
+    host: String,
+}
+
+#[derive(Builder)]
+#[builder(cli)]
+pub struct Typo {
+    #[builder(cli(hide))]
+    host: String,
+}
+
+fn main() {}
@@ -10,4 +10,51 @@ fn tests() {
     t.pass("tests/07-repeated-field.rs");
     t.compile_fail("tests/08-unrecognized-attribute.rs");
     t.pass("tests/09-redefined-prelude-types.rs");
+    t.pass("tests/10-forward-attributes.rs");
+    t.pass("tests/11-generated-docs.rs");
+    t.pass("tests/12-cfg-fields.rs");
+    t.pass("tests/13-custom-field-storage.rs");
+    t.pass("tests/14-const-builder.rs");
+    t.compile_fail("tests/15-const-builder-each.rs");
+    t.pass("tests/16-generics.rs");
+    t.pass("tests/17-hygiene-shadowed-paths.rs");
+    t.compile_fail("tests/18-unrecognized-struct-attribute.rs");
+    t.compile_fail("tests/19-each-with-custom-storage.rs");
+    t.compile_fail("tests/20-malformed-field-storage.rs");
+    t.pass("tests/21-hygiene-adversarial-names.rs");
+    t.pass("tests/22-raw-identifiers.rs");
+    t.compile_fail("tests/23-each-invalid-name.rs");
+    t.pass("tests/24-fn-builder.rs");
+    t.pass("tests/25-default.rs");
+    t.pass("tests/26-async-build.rs");
+    t.compile_fail("tests/27-default-async-without-async-build.rs");
+    t.pass("tests/28-patch.rs");
+    t.pass("tests/29-field-constraints.rs");
+    #[cfg(feature = "regex")]
+    t.pass("tests/30-regex-constraint.rs");
+    #[cfg(not(feature = "regex"))]
+    t.compile_fail("tests/31-regex-without-feature.rs");
+    t.compile_fail("tests/32-unsupported-input-shapes.rs");
+    t.compile_fail("tests/33-invalid-each.rs");
+    t.compile_fail("tests/34-method-name-collisions.rs");
+    t.pass("tests/35-required-args.rs");
+    t.pass("tests/36-field-relations.rs");
+    t.compile_fail("tests/37-invalid-field-relation.rs");
+    t.pass("tests/38-secret-fields.rs");
+    t.pass("tests/39-track-source.rs");
+    t.pass("tests/40-field-metadata.rs");
+    #[cfg(feature = "cli")]
+    t.pass("tests/41-cli.rs");
+    #[cfg(not(feature = "cli"))]
+    t.compile_fail("tests/42-cli-without-feature.rs");
+    t.pass("tests/43-setter-wrap.rs");
+    t.compile_fail("tests/44-invalid-setter-wrap.rs");
+    t.pass("tests/45-setter-aliases.rs");
+    t.compile_fail("tests/46-deprecated-alias.rs");
+    t.compile_fail("tests/47-invalid-alias.rs");
+    t.compile_fail("tests/48-invalid-patch.rs");
+    t.compile_fail("tests/49-secret-struct-attr-debug.rs");
+    t.compile_fail("tests/50-invalid-track-source-derive.rs");
+    #[cfg(feature = "cli")]
+    t.compile_fail("tests/51-cli-without-from-str.rs");
 }
@@ -1,8 +1,107 @@
-use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_macro_input, spanned::Spanned, Data, DeriveInput, Field, Fields, Ident,
+    Lit, LitStr, Meta,
+};
 
-#[proc_macro_derive(CustomDebug)]
-pub fn derive(input: TokenStream) -> TokenStream {
-    let _ = input;
+#[proc_macro_derive(CustomDebug, attributes(debug))]
+pub fn derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let inputtree = parse_macro_input!(input as DeriveInput);
+    let output = match generate_debug_impl(&inputtree) {
+        Ok(stream) => stream,
+        Err(err) => err.to_compile_error(),
+    };
+    output.into()
+}
+
+/// `Debug` that prints a field with `#[debug = "..."]` using that format
+/// string.
+fn generate_debug_impl(inputtree: &DeriveInput) -> Result<TokenStream, syn::Error> {
+    let structname = &inputtree.ident;
+    let formatter = Ident::new("f", Span::mixed_site());
+    let fieldcalls = get_named_fields(inputtree)?
+        .map(|field| {
+            let name = field
+                .ident
+                .as_ref()
+                .expect("only named fields are supported");
+            let displayname = name.unraw().to_string();
+            let value = match get_debug_format(field)? {
+                Some(format) => quote! { &::std::format_args!(#format, self.#name) },
+                None => quote! { &self.#name },
+            };
+            Ok(quote! { .field(#displayname, #value) })
+        })
+        .collect::<Result<Vec<_>, syn::Error>>()?;
+
+    let mut generics = inputtree.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(::std::fmt::Debug));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let structnamestring = structname.unraw().to_string();
+    Ok(quote! {
+        impl #impl_generics ::std::fmt::Debug for #structname #ty_generics #where_clause {
+            fn fmt(&self, #formatter: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #formatter
+                    .debug_struct(#structnamestring)
+                    #(#fieldcalls)*
+                    .finish()
+            }
+        }
+    })
+}
+
+fn get_named_fields(inputtree: &DeriveInput) -> Result<impl Iterator<Item = &Field>, syn::Error> {
+    let datastruct =
+        match &inputtree.data {
+            Data::Struct(datastruct) => datastruct,
+            Data::Enum(dataenum) => return Err(syn::Error::new(
+                dataenum.enum_token.span(),
+                "`CustomDebug` cannot be derived for an enum, only for a struct with named fields",
+            )),
+            Data::Union(dataunion) => return Err(syn::Error::new(
+                dataunion.union_token.span(),
+                "`CustomDebug` cannot be derived for a union, only for a struct with named fields",
+            )),
+        };
+    match &datastruct.fields {
+        Fields::Named(namedfields) => Ok(namedfields.named.iter()),
+        Fields::Unnamed(unnamedfields) => Err(syn::Error::new(
+            unnamedfields.span(),
+            "`CustomDebug` needs named fields; use `#[derive(Debug)]` for a tuple struct",
+        )),
+        Fields::Unit => Err(syn::Error::new(
+            inputtree.ident.span(),
+            "`CustomDebug` needs named fields; use `#[derive(Debug)]` for a unit struct",
+        )),
+    }
+}
 
-    unimplemented!()
+/// The format string of the field's `#[debug = "..."]` attribute, if any.
+fn get_debug_format(field: &Field) -> Result<Option<LitStr>, syn::Error> {
+    let mut format = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("debug"))
+    {
+        let message = "expected a format string for the field, e.g. `#[debug = \"0b{:08b}\"]`";
+        let lit = match attr.parse_meta() {
+            Ok(Meta::NameValue(value)) => match value.lit {
+                Lit::Str(lit) => lit,
+                other => return Err(syn::Error::new_spanned(other, message)),
+            },
+            _ => return Err(syn::Error::new_spanned(attr, message)),
+        };
+        if format.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "a field can only have one `#[debug = \"...\"]` attribute",
+            ));
+        }
+        format = Some(lit);
+    }
+    Ok(format)
 }
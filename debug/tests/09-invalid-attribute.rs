@@ -0,0 +1,29 @@
+// A #[debug] attribute that is not a single format string is reported at the
+// attribute, rather than being ignored or failing somewhere in the generated
+// code. CustomDebug also needs named fields, since it prints them by name.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Flag {
+    #[debug]
+    bits: u8,
+}
+
+#[derive(CustomDebug)]
+pub struct Mask {
+    #[debug = 8]
+    bits: u8,
+}
+
+#[derive(CustomDebug)]
+pub struct Register {
+    #[debug = "{:#x}"]
+    #[debug = "{:#b}"]
+    bits: u8,
+}
+
+#[derive(CustomDebug)]
+pub struct Pair(u8, u8);
+
+fn main() {}